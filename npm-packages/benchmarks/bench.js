@@ -10,6 +10,10 @@ const EMAIL_UNICODE = "foö@bücher.de";
 const EMAIL_LONG =
   "this.is.a.very-long.email@super-super.deliberately.long.and.awesome-domain.com";
 const EMAIL_OBS = "\r\n \r\n test@iana.org";
+const BATCH_SIZE = 1000;
+const BATCH = Array.from({ length: BATCH_SIZE }, (_, i) =>
+  [EMAIL, EMAIL_INVALID_LOCAL_PART, EMAIL_INVALID_DOMAIN, EMAIL_UNICODE, EMAIL_LONG][i % 5]
+);
 const DEFAULT_BENCH_OPTIONS = Object.freeze({
   iterations: 20000,
   warmupIterations: 5000,
@@ -229,7 +233,9 @@ function classifyCase(taskName) {
   const name = taskName.toLowerCase();
 
   let api = "other";
-  if (name.includes(" parse ")) api = "parse";
+  if (name.includes(" validatemanywitherrorcodes ")) api = "validate_many_with_error_codes";
+  else if (name.includes(" validatemany ")) api = "validate_many";
+  else if (name.includes(" parse ")) api = "parse";
   else if (name.includes(" isvalid ")) api = "is_valid";
   else if (name.includes(" constructor ")) api = "new";
 
@@ -239,6 +245,7 @@ function classifyCase(taskName) {
   if (name.includes("unicode")) inputCase = "unicode";
   if (name.includes("obsolete rfc 5322")) inputCase = "obs";
   if (name.includes("invalid local part")) inputCase = "invalid local part";
+  if (name.includes("batch")) inputCase = "batch";
 
   return { api, case: inputCase };
 }
@@ -366,7 +373,22 @@ function addTasks(bench, target, runtime, EmailAddress, ParsingOptions) {
     .add(`${target} constructor for obsolete RFC 5322 local-part in lax mode`, () => {
       const email = new EmailAddress("\r\n \r\n test", "iana.org", createLaxOptions());
       runtime.releaseConstructed(email);
+    })
+    .add(`${target} isValid for batch of ${BATCH_SIZE} mixed addresses`, () => {
+      for (const input of BATCH) {
+        EmailAddress.isValid(input);
+      }
     });
+
+  if (typeof EmailAddress.validateMany === "function") {
+    bench
+      .add(`${target} validateMany for batch of ${BATCH_SIZE} mixed addresses`, () => {
+        EmailAddress.validateMany(BATCH);
+      })
+      .add(`${target} validateManyWithErrorCodes for batch of ${BATCH_SIZE} mixed addresses`, () => {
+        EmailAddress.validateManyWithErrorCodes(BATCH);
+      });
+  }
 }
 
 async function runSuite(target, benchOptions, outputFormat) {
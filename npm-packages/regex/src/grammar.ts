@@ -0,0 +1,14 @@
+// Generated from `RegexSource` in rust-lib/src/regex_source.rs; do not edit.
+// Regenerate with `UPDATE_REGEX_SOURCES=1 cargo test --test regex_tests` in rust-lib.
+
+export const EMAIL_SOURCE_STRICT = "(?:(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))@(?:(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\[(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?[^\\x00\\x09\\x0A\\x0D\\x20\\x5B-\\x5D])*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\](?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))";
+
+export const EMAIL_SOURCE_LAX = "(?:(?:(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))@(?:(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\[(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?[^\\x00\\x09\\x0A\\x0D\\x20\\x5B-\\x5D])*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\](?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))|(?:(?:(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))(?:\\.(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?)))*)|(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))@(?:(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:\\.|-+)(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:\\.|-+)(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?|(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)))[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+)*)?(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?)|(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\[(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?[^\\x00\\x09\\x0A\\x0D\\x20\\x5B-\\x5D])*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\](?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?)))";
+
+export const LOCAL_PART_SOURCE_STRICT = "(?:(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))";
+
+export const LOCAL_PART_SOURCE_LAX = "(?:(?:(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))(?:\\.(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?)))*)|(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\x22(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x22\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\x22(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))";
+
+export const DOMAIN_SOURCE_STRICT = "(?:(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\[(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?[^\\x00\\x09\\x0A\\x0D\\x20\\x5B-\\x5D])*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\](?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))";
+
+export const DOMAIN_SOURCE_LAX = "(?:(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:\\.|-+)(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+(?:(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:\\.|-+)(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?|(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)))[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]+)*)?(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?)|(?:[\\x09\\x20]?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?)(?:\\.(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?(?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F](?:[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F]*[^\\x00-\\x20\\x22\\x28\\x29\\x2C\\x2D\\x2E\\x3A-\\x3C\\x3E\\x40\\x5B-\\x5D\\x7F])?))*[\\x09\\x20]?)|(?:(?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?\\[(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?[^\\x00\\x09\\x0A\\x0D\\x20\\x5B-\\x5D])*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\](?:(?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]|\\((?:(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?(?:[^\\x00\\x09\\x0A\\x0D\\x20\\x28\\x29\\x5C]|\\x5C[\\x00-\\x7F]))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\)))*(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?\\))+(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*)?|(?:(?:[\\x09\\x20]+|[\\x09\\x20]*\\r\\n[\\x09\\x20]+)(?:\\r\\n[\\x09\\x20]+)*))?))";
@@ -1,60 +1,32 @@
-const WSP = "[ \\t]";
-const OBS_NO_WS_CTL = "[\\x01-\\x08\\x0B\\x0C\\x0E-\\x1F\\x7F]";
-const NON_ASCII = "\\P{ASCII}";
-
-const ATEXT_ASCII = "[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]";
-const ATEXT_NO_DASH_ASCII = "[A-Za-z0-9!#$%&'*+/=?^_`{|}~]";
-
-const ATEXT_CHAR = `(?:${ATEXT_ASCII}|${NON_ASCII})`;
-const ATEXT_NO_DASH_CHAR = `(?:${ATEXT_NO_DASH_ASCII}|${NON_ASCII})`;
-
-const QTEXT_CHAR = `(?:[\\x21\\x23-\\x5B\\x5D-\\x7E]|${NON_ASCII}|${OBS_NO_WS_CTL})`;
-const DTEXT_CHAR = `(?:[\\x21-\\x5A\\x5E-\\x7E]|${NON_ASCII}|${OBS_NO_WS_CTL})`;
-const CTEXT_CHAR = `(?:[\\x21-\\x27\\x2A-\\x5B\\x5D-\\x7E]|${NON_ASCII}|${OBS_NO_WS_CTL})`;
-
-// Rust parser allows any ASCII octet after "\" for quoted-pair.
-const QUOTED_PAIR = "\\\\[\\x00-\\x7F]";
-
-// Mirrors parser fws(): WSP+ or WSP* CRLF WSP+, optionally repeated with CRLF WSP+.
-const FWS = `(?:${WSP}+|${WSP}*\\r\\n${WSP}+)(?:\\r\\n${WSP}+)*`;
-const COMMENT = `\\((?:(?:${FWS})?(?:${CTEXT_CHAR}|${QUOTED_PAIR}|\\((?:${CTEXT_CHAR}|${QUOTED_PAIR})*\\)))*(?:${FWS})?\\)`;
-const CFWS = `(?:${FWS}|(?:(?:(?:${FWS})?${COMMENT})+(?:${FWS})?))`;
-
-const DOT_ATOM_LABEL = `${ATEXT_NO_DASH_CHAR}(?:${ATEXT_CHAR}*${ATEXT_NO_DASH_CHAR})?`;
-const DOT_ATOM_TEXT = `${DOT_ATOM_LABEL}(?:\\.${DOT_ATOM_LABEL})*`;
-
-// Rust strict parser consumes at most one WSP char around dot-atom.
-const DOT_ATOM = `${WSP}?${DOT_ATOM_TEXT}${WSP}?`;
-
-const QCONTENT = `(?:${QTEXT_CHAR}|${QUOTED_PAIR})`;
-const QUOTED_STRING_CORE = `"(?:(?:${FWS})?${QCONTENT})*(?:${FWS})?"`;
-const QUOTED_STRING = `(?:${CFWS})?${QUOTED_STRING_CORE}(?:${CFWS})?`;
-
-const DCONTENT = `(?:${DTEXT_CHAR}|${QUOTED_PAIR})`;
-const DOMAIN_LITERAL_CORE = `\\[(?:(?:${FWS})?${DCONTENT})*(?:${FWS})?\\]`;
-const DOMAIN_LITERAL = `(?:${CFWS})?${DOMAIN_LITERAL_CORE}(?:${CFWS})?`;
-
-const LOCAL_PART_STRICT = `(?:${DOT_ATOM}|${QUOTED_STRING})`;
-const DOMAIN_STRICT = `(?:${DOT_ATOM}|${DOMAIN_LITERAL})`;
-const EMAIL_STRICT = `^${LOCAL_PART_STRICT}@${DOMAIN_STRICT}$`;
-
-const ATOM = `(?:${CFWS})?${ATEXT_CHAR}+(?:${CFWS})?`;
-const WORD = `(?:${ATOM}|${QUOTED_STRING})`;
-const OBS_LOCAL_PART = `(?:${FWS})*${WORD}(?:(?:${CFWS})*\\.(?:${CFWS})*${WORD})*`;
-
-const OBS_DOMAIN_LABEL = `${ATEXT_NO_DASH_CHAR}+`;
-const OBS_DOMAIN = `(?:` +
-  `(?:${CFWS})*${OBS_DOMAIN_LABEL}(?:(?:${CFWS})*(?:\\.|-+)(?:${CFWS})*${OBS_DOMAIN_LABEL})*(?:${FWS})*` +
-  `|${DOMAIN_LITERAL}` +
-  `)`;
-const EMAIL_LAX = `^${OBS_LOCAL_PART}@${OBS_DOMAIN}$`;
-
-export const EMAIL_REGEX_STRICT = new RegExp(EMAIL_STRICT, "u");
-export const EMAIL_REGEX_LAX = new RegExp(EMAIL_LAX, "u");
-export const LOCAL_PART_REGEX_STRICT = new RegExp(`^${LOCAL_PART_STRICT}$`, "u");
-export const LOCAL_PART_REGEX_LAX = new RegExp(`^${OBS_LOCAL_PART}$`, "u");
-export const DOMAIN_REGEX_STRICT = new RegExp(`^${DOMAIN_STRICT}$`, "u");
-export const DOMAIN_REGEX_LAX = new RegExp(`^${OBS_DOMAIN}$`, "u");
+import {
+  DOMAIN_SOURCE_LAX,
+  DOMAIN_SOURCE_STRICT,
+  EMAIL_SOURCE_LAX,
+  EMAIL_SOURCE_STRICT,
+  LOCAL_PART_SOURCE_LAX,
+  LOCAL_PART_SOURCE_STRICT,
+} from "./grammar.ts";
+
+// The unanchored sources are generated from the Rust parser's grammar; see `RegexSource` in rust-lib.
+export {
+  DOMAIN_SOURCE_LAX,
+  DOMAIN_SOURCE_STRICT,
+  EMAIL_SOURCE_LAX,
+  EMAIL_SOURCE_STRICT,
+  LOCAL_PART_SOURCE_LAX,
+  LOCAL_PART_SOURCE_STRICT,
+};
+
+function anchored(source: string): RegExp {
+  return new RegExp(`^(?:${source})$`, "u");
+}
+
+export const EMAIL_REGEX_STRICT = anchored(EMAIL_SOURCE_STRICT);
+export const EMAIL_REGEX_LAX = anchored(EMAIL_SOURCE_LAX);
+export const LOCAL_PART_REGEX_STRICT = anchored(LOCAL_PART_SOURCE_STRICT);
+export const LOCAL_PART_REGEX_LAX = anchored(LOCAL_PART_SOURCE_LAX);
+export const DOMAIN_REGEX_STRICT = anchored(DOMAIN_SOURCE_STRICT);
+export const DOMAIN_REGEX_LAX = anchored(DOMAIN_SOURCE_LAX);
 
 export function isValidEmail(email: string, isLax: boolean = false): boolean {
   return (isLax ? EMAIL_REGEX_LAX : EMAIL_REGEX_STRICT).test(email);
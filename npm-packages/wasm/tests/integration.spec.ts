@@ -3,7 +3,7 @@ const { describe, it } = require('node:test');
 
 describe('integration', function () {
   it('works', async function () {
-    const { EmailAddress, ParsingOptions } = await import('../dist/cjs/email_address_parser.js');
+    const { EmailAddress, EmailAddressParseError, ParsingOptions, ParseErrorCode } = await import('../dist/cjs/email_address_parser.js');
 
     const emailStr = 'foo@bar.com';
     const email = EmailAddress.parse(emailStr);
@@ -20,6 +20,99 @@ describe('integration', function () {
     assert.throws(() => { new EmailAddress('foo', '-bar.com'); });
     assert.throws(() => { new EmailAddress('-foo', '-bar.com'); });
 
+    assert.throws(() => { new EmailAddress('foo', '-bar.com'); }, (error: any) => {
+      assert.ok(error instanceof Error);
+      assert.ok(error instanceof EmailAddressParseError);
+      assert.strictEqual(String(error), 'EmailAddressParseError: invalid domain at offset 4');
+      assert.ok(typeof error.stack === 'string');
+      assert.strictEqual(error.name, 'EmailAddressParseError');
+      assert.strictEqual(error.kind, 'invalidDomain');
+      assert.strictEqual(error.offset, 4);
+      assert.strictEqual(error.message, 'invalid domain at offset 4');
+      return true;
+    });
+    // the module instance remains usable after an error
+    assert.strictEqual(`${new EmailAddress('foo', 'bar.com')}`, emailStr);
+
+    const ok = EmailAddress.tryParse(emailStr);
+    assert.strictEqual(ok.ok, true);
+    assert.strictEqual(`${ok.value}`, emailStr);
+    ok.value.free();
+
+    // spans are reported in UTF-8 bytes, and in UTF-16 code units for `String.prototype.slice`
+    const nonBmp = '😀.foo@bar-.com';
+    const spanned = EmailAddress.tryParse(nonBmp);
+    assert.strictEqual(spanned.ok, false);
+    assert.strictEqual(spanned.error.kind, 'invalidDomain');
+    assert.deepStrictEqual(spanned.error.span, { start: 12, end: 13 });
+    assert.deepStrictEqual(spanned.error.utf16Span, { start: 10, end: 11 });
+    assert.strictEqual(spanned.error.offset, 12);
+    assert.strictEqual(spanned.error.utf16Offset, 10);
+    assert.strictEqual(nonBmp.slice(spanned.error.utf16Span.start, spanned.error.utf16Span.end), '-');
+    const missingAt = EmailAddress.tryParse('😀');
+    assert.deepStrictEqual(missingAt.error.utf16Span, { start: 2, end: 2 });
+    assert.throws(() => { new EmailAddress('😀', '-bar.com'); }, (error: any) => {
+      assert.deepStrictEqual(error.span, { start: 5, end: 6 });
+      assert.deepStrictEqual(error.utf16Span, { start: 3, end: 4 });
+      return true;
+    });
+    const withSpans = EmailAddress.parse('😀ü@bücher.de');
+    assert.deepStrictEqual(withSpans.localPartSpan, { start: 0, end: 6 });
+    assert.deepStrictEqual(withSpans.domainSpan, { start: 7, end: 17 });
+    assert.deepStrictEqual(withSpans.localPartUtf16Span, { start: 0, end: 3 });
+    assert.deepStrictEqual(withSpans.domainUtf16Span, { start: 4, end: 13 });
+    const text = `${withSpans}`;
+    assert.strictEqual(text.slice(withSpans.domainUtf16Span.start, withSpans.domainUtf16Span.end), 'bücher.de');
+
+    const failed = EmailAddress.tryParse('foo', new ParsingOptions(true));
+    assert.strictEqual(failed.ok, false);
+    assert.strictEqual(failed.error.name, 'EmailAddressParseError');
+    assert.ok(failed.error instanceof EmailAddressParseError);
+    assert.strictEqual(failed.error.kind, 'missingAt');
+    assert.strictEqual(failed.error.offset, 3);
+
+    const parsed = EmailAddress.parse(emailStr);
+    assert.strictEqual(email1.equals(parsed), true);
+    assert.strictEqual(email1.equals(new EmailAddress('bar', 'bar.com')), false);
+    assert.strictEqual(JSON.stringify({ email: email1 }), '{"email":"foo@bar.com"}');
+    const revived = EmailAddress.fromJSON(JSON.parse(JSON.stringify(email1)));
+    assert.strictEqual(revived.equals(email1), true);
+    assert.throws(() => { EmailAddress.fromJSON('foo@-bar.com'); }, (error: any) => error.kind === 'invalidDomain');
+
+    const options = ParsingOptions.default();
+    assert.strictEqual(options.is_lax, false);
+    options.is_lax = true;
+    assert.strictEqual(options.is_lax, true);
+    assert.strictEqual(new ParsingOptions(true).is_lax, true);
+
+    const batch = [emailStr, 'foo@-bar.com', '', 'foo', 'foo..bar@bar.com', 'foö@bücher.de', 42, '\r\n \r\n test@iana.org'];
+    const results = EmailAddress.validateMany(batch as any);
+    assert.ok(results instanceof Uint8Array);
+    assert.deepStrictEqual([...results], [1, 0, 0, 0, 0, 1, 0, 0]);
+    assert.deepStrictEqual([...EmailAddress.validateMany(batch as any, new ParsingOptions(true))], [1, 0, 0, 0, 0, 1, 0, 1]);
+    assert.deepStrictEqual([...EmailAddress.validateManyWithErrorCodes(batch as any)], [
+      ParseErrorCode.Valid,
+      ParseErrorCode.InvalidDomain,
+      ParseErrorCode.Empty,
+      ParseErrorCode.MissingAt,
+      ParseErrorCode.InvalidLocalPart,
+      ParseErrorCode.Valid,
+      ParseErrorCode.Empty,
+      ParseErrorCode.InvalidLocalPart,
+    ]);
+    assert.strictEqual(EmailAddress.validateMany([]).length, 0);
+    const unicode = ['😀@bar.com', '\uD800@bar.com', 'foo@bar.com\uDC00', 'ü@ü.de', '', 'x@y.z'];
+    assert.deepStrictEqual([...EmailAddress.validateMany(unicode)], unicode.map((input) => +EmailAddress.isValid(input)));
+    const split = ['x\uD800', '\uDC00y', 'a@b.c'];
+    assert.deepStrictEqual([...EmailAddress.validateMany(split)], [0, 0, 1]);
+    assert.deepStrictEqual([...EmailAddress.validateManyWithErrorCodes(split)], [
+      ParseErrorCode.MissingAt,
+      ParseErrorCode.MissingAt,
+      ParseErrorCode.Valid,
+    ]);
+    const large = Array.from({ length: 5000 }, (_, i) => (i % 2 ? `user${i}@bücher.de` : `user${i}@-bar.com`));
+    assert.deepStrictEqual([...EmailAddress.validateMany(large)], large.map((input) => +EmailAddress.isValid(input)));
+
     assert.strictEqual(`${new EmailAddress('foö', 'bücher.de')}`, 'foö@bücher.de');
     assert.strictEqual(`${EmailAddress.parse('foö@bücher.de')}`, 'foö@bücher.de');
     assert.strictEqual(EmailAddress.isValid('foö@bücher.de'), true);
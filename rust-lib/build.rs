@@ -29,6 +29,7 @@ fn main() {
 
     let mut content = String::new();
     create_valid_parsing_tests(&mut content, &valid_local_parts, &valid_domains);
+    create_near_miss_tests(&mut content, &valid_local_parts, &valid_domains);
     create_invalid_parsing_tests(
         &mut content,
         &valid_local_parts,
@@ -111,6 +112,7 @@ macro_rules! generate_positive_parsing_test {
           assert_eq!(address.get_local_part(), $local_part, \"local_part of {}\", address_str);
           assert_eq!(address.get_domain(), $domain, \"domain of {}\", address_str);
           assert_eq!(format!(\"{}\", address), address_str, \"incorrect display\");
+          assert_eq!(EmailAddress::try_parse(&address_str, None).ok(), Some(address), \"expected {} to be parsed by try_parse\", address_str);
         }
       )*
     }
@@ -125,6 +127,48 @@ generate_positive_parsing_test!{
     content.push_str("}\n");
 }
 
+fn create_near_miss_tests(
+    content: &mut String,
+    local_parts: &Vec<String>,
+    domains: &Vec<String>,
+) {
+    content.push_str(
+        "
+macro_rules! generate_near_miss_test {
+  ($($case:ident: ($local_part:literal, $domain:literal),)+) => {
+    #[cfg(test)]
+    mod does_not_parse_near_misses {
+      use email_address_parser::*;
+      #[cfg(target_arch = \"wasm32\")]
+      use wasm_bindgen_test::*;
+      #[cfg(target_arch = \"wasm32\")]
+      wasm_bindgen_test_configure!(run_in_browser);
+      $(
+        #[test]
+        #[cfg_attr(target_arch = \"wasm32\", wasm_bindgen_test)]
+        fn $case() {
+          let address = EmailAddress::new($local_part, $domain, None).unwrap();
+          for options in [None, Some(ParsingOptions::new(true))] {
+            for near_miss in address.near_misses(options.clone()) {
+              let error = EmailAddress::try_parse(&near_miss.input, options.clone());
+              assert!(error.is_err(), \"expected {:?} not to be parsed\", near_miss.input);
+              assert_eq!(error.unwrap_err().kind(), near_miss.expected_kind(), \"error kind of {:?} ({:?})\", near_miss.input, near_miss.mutation);
+            }
+          }
+        }
+      )*
+    }
+  };
+}
+
+generate_near_miss_test!{
+",
+    );
+    create_case(content, &mut 0, local_parts, domains);
+
+    content.push_str("}\n");
+}
+
 fn create_invalid_parsing_tests(
     content: &mut String,
     valid_local_parts: &Vec<String>,
@@ -149,6 +193,7 @@ macro_rules! generate_negative_parsing_test {
         fn $case() {
           let address_str = concat!($local_part, \"@\", $domain);
           assert_eq!(EmailAddress::parse(&address_str, None).is_none(), true, \"expected {} not to be parsed\", address_str);
+          assert_eq!(EmailAddress::try_parse(&address_str, None).is_err(), true, \"expected {} to fail with an error\", address_str);
         }
       )*
     }
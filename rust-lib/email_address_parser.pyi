@@ -0,0 +1,33 @@
+from typing import Iterable, List, Optional
+
+class ParsingOptions:
+    """Options for parsing.
+
+    `is_lax` can be set to `True` or `False` to enable/disable obsolete parts parsing.
+    The default is `False`.
+    """
+
+    def __init__(self, is_lax: bool = False) -> None: ...
+    @property
+    def is_lax(self) -> bool: ...
+
+class EmailAddress:
+    """An RFC 5322, and RFC 6532 compliant email address."""
+
+    def __init__(self, local_part: str, domain: str, options: Optional[ParsingOptions] = None) -> None:
+        """Instantiates a new email address; raises `ValueError` if the local part or domain is invalid."""
+    @staticmethod
+    def parse(input: str, options: Optional[ParsingOptions] = None) -> Optional["EmailAddress"]:
+        """Parses a given string as an email address; returns `None` if it is invalid."""
+    @staticmethod
+    def is_valid(input: str, options: Optional[ParsingOptions] = None) -> bool:
+        """Validates if the given `input` string is an email address or not."""
+    @staticmethod
+    def validate_many(inputs: Iterable[object], options: Optional[ParsingOptions] = None) -> List[bool]:
+        """Validates every item of `inputs`; items that are not strings are reported as invalid."""
+    @property
+    def local_part(self) -> str: ...
+    @property
+    def domain(self) -> str: ...
+    def __copy__(self) -> "EmailAddress": ...
+    def __deepcopy__(self, memo: object) -> "EmailAddress": ...
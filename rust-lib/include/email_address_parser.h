@@ -0,0 +1,173 @@
+/* email-address-parser C API. Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#ifndef EMAIL_ADDRESS_PARSER_H
+#define EMAIL_ADDRESS_PARSER_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Status codes returned by the fallible functions.
+ */
+typedef enum EapStatus {
+  /**
+   * The operation succeeded.
+   */
+  EAP_STATUS_OK = 0,
+  /**
+   * The input is not a valid email address.
+   */
+  EAP_STATUS_INVALID_ADDRESS = 1,
+  /**
+   * A required pointer argument is `NULL`.
+   */
+  EAP_STATUS_NULL_POINTER = 2,
+  /**
+   * An input string is not valid UTF-8.
+   */
+  EAP_STATUS_INVALID_UTF8 = 3,
+} EapStatus;
+
+/**
+ * Opaque handle to a parsed email address; release it with `eap_email_address_free`.
+ */
+typedef struct EapEmailAddress EapEmailAddress;
+
+/**
+ * Options for parsing; mirrors `ParsingOptions`.
+ */
+typedef struct EapParsingOptions {
+  /**
+   * Enables lax parsing; i.e. obsolete parts as defined by RFC 5322 are allowed.
+   */
+  bool is_lax;
+} EapParsingOptions;
+
+/**
+ * A borrowed, non-NUL-terminated UTF-8 string.
+ */
+typedef struct EapStr {
+  /**
+   * Pointer to the first byte.
+   */
+  const char *ptr;
+  /**
+   * Length in bytes.
+   */
+  size_t len;
+} EapStr;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns the default parsing options; i.e. strict parsing.
+ */
+struct EapParsingOptions eap_parsing_options_default(void);
+
+/**
+ * Parses `input` as an email address.
+ *
+ * On success, stores a new handle in `*out` and returns `EAP_STATUS_OK`; `*out` is left untouched otherwise.
+ * `options` may be `NULL` for the default options.
+ *
+ * # Safety
+ * `input` must be `NULL` or a NUL-terminated string, `options` must be `NULL` or valid,
+ * and `out` must be `NULL` or valid for writes.
+ */
+enum EapStatus eap_parse(const char *input,
+                         const struct EapParsingOptions *options,
+                         struct EapEmailAddress **out);
+
+/**
+ * Instantiates an email address from a local part and a domain; mirrors `EmailAddress::new`.
+ *
+ * On success, stores a new handle in `*out` and returns `EAP_STATUS_OK`; `*out` is left untouched otherwise.
+ * `options` may be `NULL` for the default options.
+ *
+ * # Safety
+ * `local_part` and `domain` must be `NULL` or NUL-terminated strings, `options` must be `NULL` or valid,
+ * and `out` must be `NULL` or valid for writes.
+ */
+enum EapStatus eap_new(const char *local_part,
+                       const char *domain,
+                       const struct EapParsingOptions *options,
+                       struct EapEmailAddress **out);
+
+/**
+ * Returns `true` if `input` is a valid email address, `false` otherwise (including for `NULL` or non-UTF-8 input).
+ *
+ * `options` may be `NULL` for the default options.
+ *
+ * # Safety
+ * `input` must be `NULL` or a NUL-terminated string, and `options` must be `NULL` or valid.
+ */
+bool eap_is_valid(const char *input,
+                  const struct EapParsingOptions *options);
+
+/**
+ * Returns the local part of `address`, borrowed from it; `{NULL, 0}` if `address` is `NULL`.
+ *
+ * # Safety
+ * `address` must be `NULL` or a live handle.
+ */
+struct EapStr eap_email_address_local_part(const struct EapEmailAddress *address);
+
+/**
+ * Returns the domain of `address`, borrowed from it; `{NULL, 0}` if `address` is `NULL`.
+ *
+ * # Safety
+ * `address` must be `NULL` or a live handle.
+ */
+struct EapStr eap_email_address_domain(const struct EapEmailAddress *address);
+
+/**
+ * Returns the whole address, borrowed from it; `{NULL, 0}` if `address` is `NULL`.
+ *
+ * # Safety
+ * `address` must be `NULL` or a live handle.
+ */
+struct EapStr eap_email_address_as_str(const struct EapEmailAddress *address);
+
+/**
+ * Returns the whole address as a new NUL-terminated string, to be released with `eap_string_free`.
+ *
+ * Returns `NULL` if `address` is `NULL`, or if the address contains a NUL character (possible with lax parsing).
+ *
+ * # Safety
+ * `address` must be `NULL` or a live handle.
+ */
+char *eap_email_address_to_string(const struct EapEmailAddress *address);
+
+/**
+ * Releases a handle returned by `eap_parse` or `eap_new`. Passing `NULL` is a no-op.
+ *
+ * # Safety
+ * `address` must be `NULL` or a live handle, which must not be used afterwards.
+ */
+void eap_email_address_free(struct EapEmailAddress *address);
+
+/**
+ * Releases a string returned by `eap_email_address_to_string`. Passing `NULL` is a no-op.
+ *
+ * # Safety
+ * `string` must be `NULL` or a string returned by this library, which must not be used afterwards.
+ */
+void eap_string_free(char *string);
+
+/**
+ * Returns a static, NUL-terminated description of `status`, an `EapStatus` value.
+ *
+ * Takes an `int`, so that values outside of `EapStatus` are described as unknown.
+ */
+const char *eap_status_message(int status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* EMAIL_ADDRESS_PARSER_H */
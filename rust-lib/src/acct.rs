@@ -0,0 +1,495 @@
+//! `acct` URIs of [RFC 7565](https://tools.ietf.org/html/rfc7565), as used by WebFinger, and
+//! fediverse handles; both share the domain rules of email addresses.
+use crate::email_address::EmailAddress;
+use crate::nom_parser;
+use crate::parse_error::{ParseError, ParseErrorKind, UriError};
+use crate::percent_encoding::{is_sub_delim, is_unreserved, percent_decode, raw_offset, write_encoded};
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::net::Ipv6Addr;
+use core::str::FromStr;
+
+const SCHEME: &str = "acct:";
+const IPV6_TAG: &str = "IPv6:";
+
+/// An `acct` URI; e.g. `acct:alice@example.com`.
+///
+/// The user part is percent-decoded, and may contain characters that an email address cannot,
+/// such as `@`. The host is validated like the domain of an email address, without white space
+/// or comments; the only address literals are IPv6 addresses in brackets, as in other URIs,
+/// which correspond to the `[IPv6:...]` domain literals of email addresses.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let uri = AcctUri::parse("acct:juliet%40capulet.example@shoppingsite.example").unwrap();
+/// assert_eq!(uri.get_user(), "juliet@capulet.example");
+/// assert_eq!(uri.get_host(), "shoppingsite.example");
+/// assert!(uri.to_email_address().is_none());
+///
+/// let email = EmailAddress::parse("foö@bücher.de", None).unwrap();
+/// let uri = AcctUri::from_email_address(&email).unwrap();
+/// assert_eq!(uri.to_string(), "acct:fo%C3%B6@b%C3%BCcher.de");
+/// assert_eq!(uri.to_email_address(), Some(email));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AcctUri {
+    user: String,
+    host: String,
+}
+
+impl AcctUri {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Parses an `acct` URI, with a case-insensitive scheme.
+    ///
+    /// Returns a `UriError` with the byte offset into `input` at which the error was detected:
+    /// `UriError::Malformed` for a missing scheme or malformed percent-encoding, and `UriError::Invalid`
+    /// with the kinds of `EmailAddress::try_parse` for the user part, the `@`, and the host.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let uri = AcctUri::parse("ACCT:bob@example.com").unwrap();
+    /// assert_eq!(uri.get_user(), "bob");
+    ///
+    /// let error = AcctUri::parse("acct:%62ob@example.com").unwrap_err();
+    /// assert_eq!(error.parse_error().map(ParseError::kind), Some(ParseErrorKind::InvalidLocalPart));
+    /// assert_eq!(error.offset(), 5);
+    ///
+    /// let error = AcctUri::parse("mailto:bob@example.com").unwrap_err();
+    /// assert_eq!(error, UriError::Malformed(0));
+    /// ```
+    pub fn parse(input: &str) -> Result<AcctUri, UriError> {
+        if !input.get(..SCHEME.len()).is_some_and(|scheme| scheme.eq_ignore_ascii_case(SCHEME)) {
+            return Err(UriError::Malformed(0));
+        }
+        // `userpart = unreserved / sub-delims 0*( unreserved / pct-encoded / sub-delims )`
+        let bytes = input.as_bytes();
+        let start = SCHEME.len();
+        if !bytes.get(start).is_some_and(|&octet| is_unreserved(octet) || is_sub_delim(octet)) {
+            return Err(ParseError::new(ParseErrorKind::InvalidLocalPart, start).into());
+        }
+        let at = start + input[start..].find(|ch: char| !is_user_char(ch)).unwrap_or(input.len() - start);
+        match bytes.get(at) {
+            Some(b'@') => {}
+            Some(_) => return Err(ParseError::new(ParseErrorKind::InvalidLocalPart, at).into()),
+            None => return Err(ParseError::new(ParseErrorKind::MissingAt, at).into()),
+        }
+        let user = percent_decode(input, start..at)?;
+
+        let host_start = at + 1;
+        if let Some(offset) = input[host_start..].find(|ch: char| !is_host_char(ch)) {
+            return Err(ParseError::new(ParseErrorKind::InvalidDomain, host_start + offset).into());
+        }
+        let host = percent_decode(input, host_start..input.len())?;
+        parse_uri_host(&host).map_err(|offset| {
+            let offset = host_start + raw_offset(&bytes[host_start..], offset);
+            ParseError::new(ParseErrorKind::InvalidDomain, offset)
+        })?;
+        Ok(AcctUri { user, host })
+    }
+
+    /// Converts an email address to an `acct` URI, if it can be represented as one.
+    ///
+    /// The local part must start with an unreserved or sub-delimiter character (so not with a
+    /// quote or a non-ASCII character), and the domain must have no white space or comments.
+    /// Of the domain literals, only IPv6 addresses can be represented; e.g. `[IPv6:2001:db8::1]`
+    /// becomes `[2001:db8::1]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("alice@example.com", None).unwrap();
+    /// assert_eq!(AcctUri::from_email_address(&email).unwrap().to_string(), "acct:alice@example.com");
+    ///
+    /// let email = EmailAddress::parse("\"alice\"@example.com", None).unwrap();
+    /// assert!(AcctUri::from_email_address(&email).is_none());
+    ///
+    /// let email = EmailAddress::parse("alice@[IPv6:2001:db8::1]", None).unwrap();
+    /// assert_eq!(AcctUri::from_email_address(&email).unwrap().to_string(), "acct:alice@[2001:db8::1]");
+    /// ```
+    pub fn from_email_address(email: &EmailAddress) -> Option<AcctUri> {
+        let (user, domain) = (email.get_local_part(), email.get_domain());
+        let first = *user.as_bytes().first()?;
+        if !(is_unreserved(first) || is_sub_delim(first)) {
+            return None;
+        }
+        let host = match domain.strip_prefix('[') {
+            Some(literal) => {
+                let tag = literal.get(..IPV6_TAG.len()).filter(|tag| tag.eq_ignore_ascii_case(IPV6_TAG))?;
+                format!("[{}", &literal[tag.len()..])
+            }
+            None => String::from(domain),
+        };
+        parse_uri_host(&host).ok()?;
+        Some(AcctUri {
+            user: String::from(user),
+            host,
+        })
+    }
+
+    /// Converts the URI to an email address, if the user part is a valid local part.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let uri = AcctUri::parse("acct:alice@example.com").unwrap();
+    /// assert_eq!(uri.to_email_address().unwrap(), "alice@example.com");
+    ///
+    /// let uri = AcctUri::parse("acct:alice@[2001:db8::1]").unwrap();
+    /// assert_eq!(uri.to_email_address().unwrap(), "alice@[IPv6:2001:db8::1]");
+    /// ```
+    pub fn to_email_address(&self) -> Option<EmailAddress> {
+        match self.host.strip_prefix('[') {
+            Some(address) => EmailAddress::new(&self.user, &format!("[{}{}", IPV6_TAG, address), None).ok(),
+            None => EmailAddress::new(&self.user, &self.host, None).ok(),
+        }
+    }
+
+    /// Returns the percent-decoded user part of the URI.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::AcctUri;
+    ///
+    /// assert_eq!(AcctUri::parse("acct:j%C3%B6rg@x.de").unwrap().get_user(), "jörg");
+    /// ```
+    pub fn get_user(&self) -> &str {
+        &self.user
+    }
+
+    /// Returns the percent-decoded host of the URI.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::AcctUri;
+    ///
+    /// assert_eq!(AcctUri::parse("acct:bob@[2001:db8::1]").unwrap().get_host(), "[2001:db8::1]");
+    /// ```
+    pub fn get_host(&self) -> &str {
+        &self.host
+    }
+}
+
+impl FromStr for AcctUri {
+    type Err = UriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AcctUri::parse(s)
+    }
+}
+
+impl fmt::Display for AcctUri {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(SCHEME)?;
+        write_encoded(formatter, &self.user, |octet| is_unreserved(octet) || is_sub_delim(octet))?;
+        formatter.write_str("@")?;
+        write_encoded(formatter, &self.host, |octet| {
+            is_unreserved(octet) || is_sub_delim(octet) || matches!(octet, b':' | b'[' | b']')
+        })
+    }
+}
+
+/// A fediverse handle; e.g. `@alice@mastodon.social`.
+///
+/// Usernames are made of ASCII letters, digits, and `_`, with `.` and `-` allowed inside them,
+/// as Mastodon does. The instance is validated like the domain of an email address, without
+/// white space, comments, or domain literals. The leading `@` is optional when parsing.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let handle = FediverseHandle::parse("@alice@mastodon.social").unwrap();
+/// assert_eq!(handle.get_username(), "alice");
+/// assert_eq!(handle.get_instance(), "mastodon.social");
+/// assert_eq!(handle.to_acct_uri().to_string(), "acct:alice@mastodon.social");
+/// assert_eq!(handle.to_email_address().unwrap(), "alice@mastodon.social");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FediverseHandle {
+    username: String,
+    instance: String,
+}
+
+impl FediverseHandle {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Parses a fediverse handle, with or without its leading `@`.
+    ///
+    /// Returns a `ParseError` with the byte offset into `input` at which the error was detected,
+    /// of the kinds of `EmailAddress::try_parse`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// assert_eq!(FediverseHandle::parse("bob@example.com").unwrap().to_string(), "@bob@example.com");
+    ///
+    /// let error = FediverseHandle::parse("@bob.@example.com").unwrap_err();
+    /// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidLocalPart, 4));
+    ///
+    /// let error = FediverseHandle::parse("@bob").unwrap_err();
+    /// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::MissingAt, 4));
+    /// ```
+    pub fn parse(input: &str) -> Result<FediverseHandle, ParseError> {
+        if input.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::Empty, 0));
+        }
+        let start = if input.starts_with('@') { 1 } else { 0 };
+        let at = start + input[start..].find(|ch: char| !is_username_char(ch)).unwrap_or(input.len() - start);
+        if let Err(offset) = parse_username(&input[start..at]) {
+            return Err(ParseError::new(ParseErrorKind::InvalidLocalPart, start + offset));
+        }
+        match input.as_bytes().get(at) {
+            Some(b'@') => {}
+            Some(_) => return Err(ParseError::new(ParseErrorKind::InvalidLocalPart, at)),
+            None => return Err(ParseError::new(ParseErrorKind::MissingAt, at)),
+        }
+        let instance = &input[at + 1..];
+        if let Err(offset) = parse_instance(instance) {
+            return Err(ParseError::new(ParseErrorKind::InvalidDomain, at + 1 + offset));
+        }
+        Ok(FediverseHandle {
+            username: String::from(&input[start..at]),
+            instance: String::from(instance),
+        })
+    }
+
+    /// Converts an email address to a fediverse handle, if its local part is a valid username
+    /// and its domain a valid instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("alice@example.com", None).unwrap();
+    /// assert_eq!(FediverseHandle::from_email_address(&email).unwrap().to_string(), "@alice@example.com");
+    ///
+    /// let email = EmailAddress::parse("alice+news@example.com", None).unwrap();
+    /// assert!(FediverseHandle::from_email_address(&email).is_none());
+    /// ```
+    pub fn from_email_address(email: &EmailAddress) -> Option<FediverseHandle> {
+        let (username, instance) = (email.get_local_part(), email.get_domain());
+        parse_username(username).ok()?;
+        parse_instance(instance).ok()?;
+        Some(FediverseHandle {
+            username: String::from(username),
+            instance: String::from(instance),
+        })
+    }
+
+    /// Converts the handle to an email address, if the username is a valid local part; i.e.
+    /// unless it contains consecutive dots.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let handle = FediverseHandle::parse("@a..b@example.com").unwrap();
+    /// assert!(handle.to_email_address().is_none());
+    /// ```
+    pub fn to_email_address(&self) -> Option<EmailAddress> {
+        EmailAddress::new(&self.username, &self.instance, None).ok()
+    }
+
+    /// Converts the handle to the `acct` URI that WebFinger looks it up with.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let handle = FediverseHandle::parse("@alice@bücher.social").unwrap();
+    /// assert_eq!(handle.to_acct_uri().to_string(), "acct:alice@b%C3%BCcher.social");
+    /// ```
+    pub fn to_acct_uri(&self) -> AcctUri {
+        AcctUri {
+            user: self.username.clone(),
+            host: self.instance.clone(),
+        }
+    }
+
+    /// Returns the username of the handle.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::FediverseHandle;
+    ///
+    /// assert_eq!(FediverseHandle::parse("@alice@example.com").unwrap().get_username(), "alice");
+    /// ```
+    pub fn get_username(&self) -> &str {
+        &self.username
+    }
+
+    /// Returns the instance of the handle.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::FediverseHandle;
+    ///
+    /// assert_eq!(FediverseHandle::parse("@alice@example.com").unwrap().get_instance(), "example.com");
+    /// ```
+    pub fn get_instance(&self) -> &str {
+        &self.instance
+    }
+}
+
+impl FromStr for FediverseHandle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FediverseHandle::parse(s)
+    }
+}
+
+impl fmt::Display for FediverseHandle {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "@{}@{}", self.username, self.instance)
+    }
+}
+
+fn is_user_char(ch: char) -> bool {
+    ch == '%' || (ch.is_ascii() && (is_unreserved(ch as u8) || is_sub_delim(ch as u8)))
+}
+
+fn is_host_char(ch: char) -> bool {
+    matches!(ch, '%' | ':' | '[' | ']') || (ch.is_ascii() && (is_unreserved(ch as u8) || is_sub_delim(ch as u8)))
+}
+
+fn is_username_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '-')
+}
+
+// `[a-z0-9_]+([a-z0-9_.-]+[a-z0-9_]+)?`, case-insensitive; returns the offset of the first invalid character.
+fn parse_username(username: &str) -> Result<(), usize> {
+    let is_edge = |ch: char| ch.is_ascii_alphanumeric() || ch == '_';
+    if let Some(offset) = username.find(|ch: char| !is_username_char(ch)) {
+        return Err(offset);
+    }
+    match (username.chars().next(), username.chars().last()) {
+        (Some(first), _) if !is_edge(first) => Err(0),
+        (Some(_), Some(last)) if !is_edge(last) => Err(username.len() - 1),
+        (Some(_), _) => Ok(()),
+        (None, _) => Err(0),
+    }
+}
+
+// URIs and handles have no white space or comments, which `domain_strict` allows around atoms.
+fn parse_host(host: &str) -> Result<(), usize> {
+    match host.find(|ch: char| ch.is_whitespace() || ch.is_control() || ch == '(' || ch == ')') {
+        Some(offset) => Err(offset),
+        None => nom_parser::parse_domain(host),
+    }
+}
+
+// `IP-literal` of RFC 3986, without `IPvFuture`: unlike the domain literals of email addresses,
+// it is an untagged IPv6 address.
+fn parse_uri_host(host: &str) -> Result<(), usize> {
+    match host.strip_prefix('[') {
+        Some(literal) => match literal.strip_suffix(']').map(str::parse::<Ipv6Addr>) {
+            Some(Ok(_)) => Ok(()),
+            _ => Err(1),
+        },
+        None => parse_host(host),
+    }
+}
+
+fn parse_instance(instance: &str) -> Result<(), usize> {
+    if instance.starts_with('[') {
+        return Err(0);
+    }
+    parse_host(instance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acct_uri_reports_kind_and_offset() {
+        let invalid = |kind, offset| UriError::Invalid(ParseError::new(kind, offset));
+        let cases = [
+            ("mailto:bob@example.com", UriError::Malformed(0)),
+            ("acct:", invalid(ParseErrorKind::InvalidLocalPart, 5)),
+            ("acct:@example.com", invalid(ParseErrorKind::InvalidLocalPart, 5)),
+            ("acct:bob", invalid(ParseErrorKind::MissingAt, 8)),
+            ("acct:b/ob@example.com", invalid(ParseErrorKind::InvalidLocalPart, 6)),
+            ("acct:b%4@example.com", UriError::Malformed(6)),
+            ("acct:b%FF@example.com", invalid(ParseErrorKind::InvalidUtf8, 6)),
+            ("acct:bob@", invalid(ParseErrorKind::InvalidDomain, 9)),
+            ("acct:bob@exa mple.com", invalid(ParseErrorKind::InvalidDomain, 12)),
+            ("acct:bob@example%20.com", invalid(ParseErrorKind::InvalidDomain, 16)),
+            ("acct:bob@b%C3%BCcher..de", invalid(ParseErrorKind::InvalidDomain, 21)),
+            ("acct:bob@[IPv6:2001:db8::1]", invalid(ParseErrorKind::InvalidDomain, 10)),
+            ("acct:bob@[192.0.2.1]", invalid(ParseErrorKind::InvalidDomain, 10)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(AcctUri::parse(input).unwrap_err(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn acct_uri_round_trips() {
+        for input in [
+            "acct:foobar@status.example.net",
+            "acct:juliet%40capulet.example@shoppingsite.example",
+            "acct:a!$&'()*+,;=~._-@example.com",
+            "acct:j%C3%B6rg@b%C3%BCcher.de",
+            "acct:bob@[2001:db8::1]",
+        ] {
+            assert_eq!(AcctUri::parse(input).unwrap().to_string(), input);
+        }
+        let uri = AcctUri::parse("acct:%E2%82%AC@x.com").unwrap_err();
+        assert_eq!(uri.parse_error().map(ParseError::kind), Some(ParseErrorKind::InvalidLocalPart));
+    }
+
+    #[test]
+    fn acct_uri_maps_ipv6_literals() {
+        let email = EmailAddress::parse("bob@[ipv6:2001:db8::1]", None).unwrap();
+        let uri = AcctUri::from_email_address(&email).unwrap();
+        assert_eq!(uri.to_string(), "acct:bob@[2001:db8::1]");
+        assert_eq!(uri.to_email_address().unwrap(), "bob@[IPv6:2001:db8::1]");
+        for domain in ["[192.0.2.1]", "[IPv6:192.0.2.1]", "[x:y]"] {
+            let email = EmailAddress::new("bob", domain, None).unwrap();
+            assert_eq!(AcctUri::from_email_address(&email), None, "{:?}", domain);
+        }
+    }
+
+    #[test]
+    fn fediverse_handle_reports_kind_and_offset() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("@", ParseErrorKind::InvalidLocalPart, 1),
+            ("@@example.com", ParseErrorKind::InvalidLocalPart, 1),
+            ("@.bob@example.com", ParseErrorKind::InvalidLocalPart, 1),
+            ("@bo+b@example.com", ParseErrorKind::InvalidLocalPart, 3),
+            ("@bob@", ParseErrorKind::InvalidDomain, 5),
+            ("@bob@[192.0.2.1]", ParseErrorKind::InvalidDomain, 5),
+            ("@bob@example.com-", ParseErrorKind::InvalidDomain, 16),
+            ("@bob@example. com", ParseErrorKind::InvalidDomain, 13),
+        ];
+        for (input, kind, offset) in cases {
+            let error = FediverseHandle::parse(input).unwrap_err();
+            assert_eq!((error.kind(), error.offset()), (kind, offset), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn fediverse_handle_converts_to_email_address() {
+        for input in ["@a_b@example.com", "@a.b-c@x.social", "@Gargron@mastodon.social", "@a@bücher.de"] {
+            let handle = FediverseHandle::parse(input).unwrap();
+            assert_eq!(handle.to_string(), input);
+            let email = handle.to_email_address().unwrap();
+            assert_eq!(FediverseHandle::from_email_address(&email), Some(handle.clone()));
+            assert_eq!(handle.to_acct_uri().to_email_address(), Some(email));
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use crate::email_address::{EmailAddress, ParsingOptions};
+use crate::nom_parser;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+/// Validation outcome of a single item of a batch.
+///
+/// Produced by [`EmailAddress::validate_many`] and, with the `rayon` feature, by `EmailAddress::par_validate_many`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValidationResult {
+    /// Zero-based position of the item in the input sequence.
+    pub index: usize,
+    /// `true` if the item is a valid email address, `false` otherwise.
+    pub is_valid: bool,
+}
+
+/// Validation outcome of a single line read by [`EmailAddress::validate_lines`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineValidationResult {
+    /// One-based line number in the input.
+    pub line_number: usize,
+    /// `true` if the line, without its line terminator, is a valid email address, `false` otherwise.
+    pub is_valid: bool,
+}
+
+/// Iterator returned by [`EmailAddress::validate_many`].
+#[derive(Debug)]
+pub struct ValidateMany<I> {
+    inputs: core::iter::Enumerate<I>,
+    is_lax: bool,
+}
+
+impl<I> Iterator for ValidateMany<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = ValidationResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, input) = self.inputs.next()?;
+        Some(ValidationResult {
+            index,
+            is_valid: nom_parser::parse_address(input.as_ref(), self.is_lax).is_some(),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
+
+/// Iterator returned by [`EmailAddress::validate_lines`].
+///
+/// A single line buffer is reused for the whole stream.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ValidateLines<R> {
+    reader: R,
+    line: Vec<u8>,
+    line_number: usize,
+    is_lax: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> Iterator for ValidateLines<R> {
+    type Item = io::Result<LineValidationResult>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        match self.reader.read_until(b'\n', &mut self.line) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.line_number += 1;
+                let line = self.line.strip_suffix(b"\n").unwrap_or(&self.line);
+                let line = line.strip_suffix(b"\r").unwrap_or(line);
+                let Ok(line) = core::str::from_utf8(line) else {
+                    let message = format!("line {} is not valid UTF-8", self.line_number);
+                    return Some(Err(io::Error::new(io::ErrorKind::InvalidData, message)));
+                };
+                Some(Ok(LineValidationResult {
+                    line_number: self.line_number,
+                    is_valid: nom_parser::parse_address(line, self.is_lax).is_some(),
+                }))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Validates every item of `inputs`, yielding one `ValidationResult` per item, in order.
+    ///
+    /// The returned iterator is lazy, and does not allocate per item.
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let results: Vec<ValidationResult> =
+    ///     EmailAddress::validate_many(&["foo@bar.com", "foo@-bar.com"], None).collect();
+    /// assert_eq!(results[0], ValidationResult { index: 0, is_valid: true });
+    /// assert_eq!(results[1], ValidationResult { index: 1, is_valid: false });
+    /// ```
+    pub fn validate_many<I>(inputs: I, options: Option<ParsingOptions>) -> ValidateMany<I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        ValidateMany {
+            inputs: inputs.into_iter().enumerate(),
+            is_lax: options.unwrap_or_default().is_lax,
+        }
+    }
+
+    /// Validates every line read from `reader`, yielding one `LineValidationResult` per line.
+    ///
+    /// Line terminators (`\n` or `\r\n`) are not considered part of the address.
+    /// I/O errors are yielded as-is, and lines that are not valid UTF-8 as `io::ErrorKind::InvalidData`
+    /// errors; either way, the following lines keep their numbers. The line buffer is reused between lines.
+    ///
+    /// Only available with the `std` feature. Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let input = "foo@bar.com\r\nfoo@-bar.com\n";
+    /// let invalid_lines: Vec<usize> = EmailAddress::validate_lines(input.as_bytes(), None)
+    ///     .map(|result| result.unwrap())
+    ///     .filter(|result| !result.is_valid)
+    ///     .map(|result| result.line_number)
+    ///     .collect();
+    /// assert_eq!(invalid_lines, vec![2]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn validate_lines<R: BufRead>(reader: R, options: Option<ParsingOptions>) -> ValidateLines<R> {
+        ValidateLines {
+            reader,
+            line: Vec::new(),
+            line_number: 0,
+            is_lax: options.unwrap_or_default().is_lax,
+        }
+    }
+
+    /// Validates every item of `inputs` in parallel using rayon.
+    ///
+    /// The results are in input order, and carry the index of the item.
+    ///
+    /// Only available with the `rayon` feature. Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let inputs = vec!["foo@bar.com", "foo@-bar.com"];
+    /// let results: Vec<ValidationResult> = EmailAddress::par_validate_many(&inputs, None).collect();
+    /// assert!(results[0].is_valid);
+    /// assert!(!results[1].is_valid);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_validate_many<I>(
+        inputs: I,
+        options: Option<ParsingOptions>,
+    ) -> impl IndexedParallelIterator<Item = ValidationResult>
+    where
+        I: IntoParallelIterator,
+        I::Iter: IndexedParallelIterator,
+        I::Item: AsRef<str>,
+    {
+        let is_lax = options.unwrap_or_default().is_lax;
+        inputs
+            .into_par_iter()
+            .enumerate()
+            .map(move |(index, input)| ValidationResult {
+                index,
+                is_valid: nom_parser::parse_address(input.as_ref(), is_lax).is_some(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_many_reports_index_and_validity() {
+        let inputs = vec![
+            String::from("foo@bar.com"),
+            String::from("test@ iana .com"),
+            String::from("test@-iana.org"),
+        ];
+        let strict: Vec<bool> = EmailAddress::validate_many(&inputs, None)
+            .map(|result| result.is_valid)
+            .collect();
+        assert_eq!(strict, vec![true, false, false]);
+
+        let lax: Vec<ValidationResult> =
+            EmailAddress::validate_many(inputs.iter(), Some(ParsingOptions::new(true))).collect();
+        assert_eq!(lax.len(), 3);
+        assert_eq!(lax[1], ValidationResult { index: 1, is_valid: true });
+        assert_eq!(lax[2], ValidationResult { index: 2, is_valid: false });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn validate_lines_reports_line_numbers() {
+        let input = "foo@bar.com\n\nfoo@-bar.com\r\nfoö@bücher.de";
+        let results: Vec<LineValidationResult> = EmailAddress::validate_lines(input.as_bytes(), None)
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(
+            results,
+            vec![
+                LineValidationResult { line_number: 1, is_valid: true },
+                LineValidationResult { line_number: 2, is_valid: false },
+                LineValidationResult { line_number: 3, is_valid: false },
+                LineValidationResult { line_number: 4, is_valid: true },
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn validate_lines_counts_lines_that_are_not_utf8() {
+        let input: &[u8] = b"a@b.com\n\xff\xfe\nfoo@-bar.com\nc@d.com\n";
+        let results: Vec<io::Result<LineValidationResult>> = EmailAddress::validate_lines(input, None).collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &LineValidationResult { line_number: 1, is_valid: true });
+        let error = results[1].as_ref().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "line 2 is not valid UTF-8");
+        assert_eq!(results[2].as_ref().unwrap(), &LineValidationResult { line_number: 3, is_valid: false });
+        assert_eq!(results[3].as_ref().unwrap(), &LineValidationResult { line_number: 4, is_valid: true });
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_validate_many_matches_validate_many() {
+        let inputs: Vec<String> = (0..1000)
+            .map(|i| if i % 3 == 0 { format!("foo{}@-bar.com", i) } else { format!("foo{}@bar.com", i) })
+            .collect();
+        let sequential: Vec<ValidationResult> = EmailAddress::validate_many(&inputs, None).collect();
+        let parallel: Vec<ValidationResult> = EmailAddress::par_validate_many(&inputs, None).collect();
+        assert_eq!(sequential, parallel);
+    }
+}
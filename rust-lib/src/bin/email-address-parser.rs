@@ -0,0 +1,326 @@
+//! Command-line email address validator.
+//!
+//! Validates addresses given as arguments, read line by line from files or stdin,
+//! or read from a column of CSV input, and reports pass/fail with a reason.
+//!
+//! Exit codes:
+//! - `0`: every address is valid,
+//! - `1`: at least one address is invalid,
+//! - `2`: usage or I/O error.
+use clap::{Parser, ValueEnum};
+use email_address_parser::{EmailAddress, ParsingOptions};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::ExitCode;
+
+const STDIN_SOURCE: &str = "-";
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "email-address-parser",
+    version,
+    about = "Validates email addresses as per RFC 5322 and RFC 6532.",
+    after_help = "Exit status is 0 if every address is valid, 1 if any address is invalid, and 2 on usage or I/O errors."
+)]
+struct Cli {
+    /// Addresses to validate. When neither addresses nor files are given, addresses are read from stdin.
+    addresses: Vec<String>,
+
+    /// Reads addresses from FILE, one per line; `-` reads stdin. Can be repeated. Blank lines are skipped.
+    #[arg(short, long = "file", value_name = "FILE")]
+    files: Vec<String>,
+
+    /// Enables lax parsing; i.e. obsolete parts as defined by RFC 5322 are allowed.
+    #[arg(long, conflicts_with = "strict")]
+    lax: bool,
+
+    /// Enables strict parsing; i.e. obsolete parts as defined by RFC 5322 are not allowed. This is the default.
+    #[arg(long)]
+    strict: bool,
+
+    /// Output format.
+    #[arg(short = 'o', long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Treats files and stdin as CSV, and validates COLUMN; either a header name or a 1-based index.
+    #[arg(short, long)]
+    column: Option<String>,
+
+    /// The CSV input has no header row; COLUMN must then be an index.
+    #[arg(long, requires = "column")]
+    no_header: bool,
+
+    /// Reports only invalid addresses.
+    #[arg(long)]
+    only_invalid: bool,
+
+    /// Prints nothing; only the exit status is set.
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// Human-readable text.
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// CSV with a header row.
+    Csv,
+}
+
+/// Validation outcome of one input address.
+struct Outcome<'a> {
+    source: &'a str,
+    line: u64,
+    address: &'a str,
+    reason: Option<String>,
+}
+
+enum Output<W: Write> {
+    Lines(W),
+    Csv(Box<csv::Writer<W>>),
+}
+
+/// Writes outcomes in the selected format, and tracks whether any address was invalid.
+struct Reporter<W: Write> {
+    format: Format,
+    only_invalid: bool,
+    quiet: bool,
+    found_invalid: bool,
+    output: Output<W>,
+}
+
+impl<W: Write> Reporter<W> {
+    fn new(cli: &Cli, writer: W) -> io::Result<Self> {
+        let output = if cli.format == Format::Csv {
+            let mut writer = csv::Writer::from_writer(writer);
+            if !cli.quiet {
+                writer.write_record(["source", "line", "address", "valid", "reason"])?;
+            }
+            Output::Csv(Box::new(writer))
+        } else {
+            Output::Lines(writer)
+        };
+        Ok(Reporter {
+            format: cli.format,
+            only_invalid: cli.only_invalid,
+            quiet: cli.quiet,
+            found_invalid: false,
+            output,
+        })
+    }
+
+    fn report(&mut self, outcome: Outcome<'_>) -> io::Result<()> {
+        let is_valid = outcome.reason.is_none();
+        self.found_invalid |= !is_valid;
+        if self.quiet || (self.only_invalid && is_valid) {
+            return Ok(());
+        }
+        match (&mut self.output, self.format) {
+            (Output::Lines(writer), Format::Text) => {
+                write!(writer, "{}:{}: ", outcome.source, outcome.line)?;
+                match outcome.reason {
+                    None => writeln!(writer, "PASS {}", outcome.address.escape_debug()),
+                    Some(reason) => {
+                        writeln!(writer, "FAIL {} ({})", outcome.address.escape_debug(), reason)
+                    }
+                }
+            }
+            (Output::Lines(writer), _) => writeln!(
+                writer,
+                "{{\"source\":{},\"line\":{},\"address\":{},\"valid\":{},\"reason\":{}}}",
+                json_string(outcome.source),
+                outcome.line,
+                json_string(outcome.address),
+                is_valid,
+                outcome.reason.as_deref().map_or_else(|| String::from("null"), json_string),
+            ),
+            (Output::Csv(writer), _) => {
+                let line = outcome.line.to_string();
+                let valid = if is_valid { "true" } else { "false" };
+                writer.write_record([
+                    outcome.source,
+                    line.as_str(),
+                    outcome.address,
+                    valid,
+                    outcome.reason.as_deref().unwrap_or(""),
+                ])?;
+                Ok(())
+            }
+        }
+    }
+
+    fn finish(self) -> io::Result<bool> {
+        match self.output {
+            Output::Lines(mut writer) => writer.flush()?,
+            Output::Csv(mut writer) => writer.flush()?,
+        }
+        Ok(self.found_invalid)
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Validates every input, and returns `true` if any address is invalid.
+fn run(cli: &Cli) -> Result<bool, String> {
+    let options = ParsingOptions::new(cli.lax);
+    let mut reporter = Reporter::new(cli, io::stdout().lock()).map_err(|e| e.to_string())?;
+
+    for (index, address) in cli.addresses.iter().enumerate() {
+        reporter
+            .report(Outcome {
+                source: "arg",
+                line: index as u64 + 1,
+                address,
+                reason: invalid_reason(address, &options),
+            })
+            .map_err(|e| e.to_string())?;
+    }
+
+    let mut files: Vec<&str> = cli.files.iter().map(String::as_str).collect();
+    if files.is_empty() && cli.addresses.is_empty() {
+        files.push(STDIN_SOURCE);
+    }
+    for source in files {
+        let reader: Box<dyn BufRead> = if source == STDIN_SOURCE {
+            Box::new(io::stdin().lock())
+        } else {
+            let file = File::open(source).map_err(|e| format!("{}: {}", source, e))?;
+            Box::new(BufReader::new(file))
+        };
+        let result = match &cli.column {
+            Some(column) => validate_csv(reader, source, column, cli.no_header, &options, &mut reporter),
+            None => validate_lines(reader, source, &options, &mut reporter).map_err(|e| e.to_string()),
+        };
+        result.map_err(|e| format!("{}: {}", source, e))?;
+    }
+
+    reporter.finish().map_err(|e| e.to_string())
+}
+
+fn validate_lines<W: Write>(
+    mut reader: impl BufRead,
+    source: &str,
+    options: &ParsingOptions,
+    reporter: &mut Reporter<W>,
+) -> io::Result<()> {
+    let mut buffer = Vec::new();
+    let mut line = 0;
+    loop {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            return Ok(());
+        }
+        line += 1;
+        let bytes = buffer.strip_suffix(b"\n").unwrap_or(&buffer);
+        let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+        if bytes.is_empty() {
+            continue;
+        }
+        report_bytes(bytes, source, line, options, reporter)?;
+    }
+}
+
+fn validate_csv<W: Write>(
+    reader: impl BufRead,
+    source: &str,
+    column: &str,
+    no_header: bool,
+    options: &ParsingOptions,
+    reporter: &mut Reporter<W>,
+) -> Result<(), String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(!no_header)
+        .flexible(true)
+        .from_reader(reader);
+    let index = match column.parse::<usize>() {
+        Ok(0) => return Err(String::from("column indices start at 1")),
+        Ok(index) => index - 1,
+        Err(_) if no_header => return Err(format!("column '{}' must be an index with --no-header", column)),
+        Err(_) => reader
+            .byte_headers()
+            .map_err(|e| e.to_string())?
+            .iter()
+            .position(|header| header == column.as_bytes())
+            .ok_or_else(|| format!("column '{}' not found in the header", column))?,
+    };
+
+    let mut record = csv::ByteRecord::new();
+    while reader.read_byte_record(&mut record).map_err(|e| e.to_string())? {
+        let line = record.position().map_or(0, |position| position.line());
+        let result = match record.get(index) {
+            Some(field) => report_bytes(field, source, line, options, reporter),
+            None => reporter.report(Outcome {
+                source,
+                line,
+                address: "",
+                reason: Some(format!("missing column {}", index + 1)),
+            }),
+        };
+        result.map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn report_bytes<W: Write>(
+    bytes: &[u8],
+    source: &str,
+    line: u64,
+    options: &ParsingOptions,
+    reporter: &mut Reporter<W>,
+) -> io::Result<()> {
+    match std::str::from_utf8(bytes) {
+        Ok(address) => reporter.report(Outcome {
+            source,
+            line,
+            address,
+            reason: invalid_reason(address, options),
+        }),
+        Err(_) => reporter.report(Outcome {
+            source,
+            line,
+            address: &String::from_utf8_lossy(bytes),
+            reason: EmailAddress::parse_bytes(bytes, Some(options.clone()), false).err().map(|e| e.to_string()),
+        }),
+    }
+}
+
+/// Returns `None` for a valid address, or a short explanation why it is invalid.
+fn invalid_reason(address: &str, options: &ParsingOptions) -> Option<String> {
+    let error = EmailAddress::try_parse(address, Some(options.clone())).err()?;
+    if !options.is_lax && EmailAddress::is_valid(address, Some(ParsingOptions::new(true))) {
+        return Some(String::from("obsolete syntax; valid only with --lax"));
+    }
+    Some(error.to_string())
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 || ch == '\u{7f}' => {
+                escaped.push_str(&format!("\\u{:04x}", ch as u32))
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
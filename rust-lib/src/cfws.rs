@@ -0,0 +1,113 @@
+//! Comments and folding white space (CFWS) of RFC 5322, which lax parsing allows around the
+//! atoms of local parts and domains, and the tokens they separate.
+use alloc::string::String;
+use core::ops::Range;
+
+/// Reads a quoted string up to its closing `"`, resolving quoted pairs; returns the rest after it.
+pub(crate) fn unquote(input: &str) -> (String, &str) {
+    let mut text = String::new();
+    let mut chars = input.char_indices();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '"' => return (text, &input[index + 1..]),
+            '\\' => text.extend(chars.next().map(|(_, ch)| ch)),
+            '\r' | '\n' => {}
+            _ => text.push(ch),
+        }
+    }
+    (text, "")
+}
+
+/// Skips a possibly nested comment, up to its closing `)`; returns the rest after it.
+pub(crate) fn skip_comment(input: &str) -> &str {
+    let mut depth = 1;
+    let mut chars = input.char_indices();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '(' => depth += 1,
+            ')' if depth == 1 => return &input[index + 1..],
+            ')' => depth -= 1,
+            '\\' => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+/// A token of a valid local part or domain, as split by [`tokens`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Token {
+    /// A possibly nested comment, including its parentheses.
+    Comment,
+    /// A run of white space, including the line breaks of folding white space.
+    WhiteSpace,
+    /// A quoted string, including its quotes.
+    QuotedString,
+    /// A domain literal, including its brackets.
+    DomainLiteral,
+    /// A `.` between atoms, quoted strings, or labels.
+    Dot,
+    /// An atom of a local part, or a label of a domain.
+    Atom,
+}
+
+fn is_wsp(ch: char) -> bool {
+    matches!(ch, ' ' | '\t' | '\r' | '\n')
+}
+
+/// Splits a valid local part or domain into its tokens, with their byte ranges in `text`.
+pub(crate) fn tokens(text: &str) -> impl Iterator<Item = (Token, Range<usize>)> + '_ {
+    let mut index = 0;
+    core::iter::from_fn(move || {
+        let start = index;
+        let ch = text[start..].chars().next()?;
+        let find_end = |is_end: fn(char) -> bool| text[start..].find(is_end).map_or(text.len(), |end| start + end);
+        let (token, end) = match ch {
+            '(' => (Token::Comment, text.len() - skip_comment(&text[start + 1..]).len()),
+            '"' => (Token::QuotedString, text.len() - unquote(&text[start + 1..]).1.len()),
+            '[' => (Token::DomainLiteral, text[start..].find(']').map_or(text.len(), |close| start + close + 1)),
+            '.' => (Token::Dot, start + 1),
+            ch if is_wsp(ch) => (Token::WhiteSpace, find_end(|ch| !is_wsp(ch))),
+            _ => (Token::Atom, find_end(|ch| is_wsp(ch) || matches!(ch, '.' | '(' | '"' | '['))),
+        };
+        index = end;
+        Some((token, start..end))
+    })
+}
+
+/// Drops the comments and white space (CFWS) of a valid local part or domain, which lax parsing
+/// allows around its atoms; e.g. `(x) john . doe` becomes `john.doe`.
+pub(crate) fn strip_cfws(text: &str) -> String {
+    tokens(text)
+        .filter(|(token, _)| !matches!(token, Token::Comment | Token::WhiteSpace))
+        .map(|(_, range)| &text[range])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    #[test]
+    fn strips_comments_and_white_space() {
+        assert_eq!(strip_cfws("(x) john . \"(not a comment)\"\r\n (y(z)).doe"), "john.\"(not a comment)\".doe");
+        assert_eq!(strip_cfws(" [192.0.2.1] (home)"), "[192.0.2.1]");
+        assert_eq!(strip_cfws("example.com"), "example.com");
+        let text = "a (b) \"c\".d";
+        let tokens: Vec<(Token, &str)> = tokens(text).map(|(token, range)| (token, &text[range])).collect();
+        assert_eq!(
+            tokens,
+            [
+                (Token::Atom, "a"),
+                (Token::WhiteSpace, " "),
+                (Token::Comment, "(b)"),
+                (Token::WhiteSpace, " "),
+                (Token::QuotedString, "\"c\""),
+                (Token::Dot, "."),
+                (Token::Atom, "d"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,388 @@
+//! Recovery of addresses that people obfuscate against scrapers; e.g. `john [at] example [dot] com`.
+use crate::email_address::{EmailAddress, ParsingOptions};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Above this many substitutions, only the candidate applying all of them is tried.
+const MAX_OPTIONAL_SUBSTITUTIONS: usize = 10;
+/// The confidence is multiplied by this for every recognised pattern left as it is.
+const SKIPPED_PENALTY: f64 = 0.5;
+
+/// A pattern that stands for an `@` or a `.` in an obfuscated address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Obfuscation {
+    /// `at` or `dot` in brackets, parentheses, or braces; e.g. `john [at] example (dot) com`.
+    BracketedWord,
+    /// `at` or `dot` as a word between white space; e.g. `john at example dot com`.
+    SpelledWord,
+    /// An HTML character reference; e.g. `john&#64;example&#x2E;com` or `john&commat;example.com`.
+    HtmlEntity,
+    /// `@` or `.` with white space around it; e.g. `john @ example . com`.
+    SpacedSymbol,
+}
+
+impl Obfuscation {
+    /// Returns how likely it is that a match of the pattern stands for the symbol, from 0 to 1.
+    ///
+    /// Spelled words are the least likely, as `at` and `dot` also appear in prose.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// assert!(Obfuscation::SpelledWord.confidence() < Obfuscation::BracketedWord.confidence());
+    /// ```
+    pub fn confidence(&self) -> f64 {
+        match self {
+            Obfuscation::BracketedWord => 0.95,
+            Obfuscation::SpelledWord => 0.8,
+            Obfuscation::HtmlEntity => 0.99,
+            Obfuscation::SpacedSymbol => 0.9,
+        }
+    }
+
+    /// Returns the length and the symbol of a match of the pattern at the start of `input`.
+    /// `is_inner` tells whether `input` is preceded by something that the symbol can follow.
+    fn match_at(&self, input: &str, is_inner: bool) -> Option<(usize, char)> {
+        match self {
+            Obfuscation::BracketedWord => {
+                let open = skip_white_space(input, 0);
+                let close = match input[open..].chars().next()? {
+                    '[' => ']',
+                    '(' => ')',
+                    '{' => '}',
+                    _ => return None,
+                };
+                let (end, symbol) = keyword(input, skip_white_space(input, open + 1))?;
+                let end = skip_white_space(input, end);
+                input[end..].strip_prefix(close)?;
+                Some((skip_white_space(input, end + 1), symbol))
+            }
+            Obfuscation::SpelledWord => {
+                let start = skip_white_space(input, 0);
+                let (end, symbol) = keyword(input, start)?;
+                let next = skip_white_space(input, end);
+                (is_inner && start > 0 && next > end && next < input.len()).then_some((next, symbol))
+            }
+            Obfuscation::HtmlEntity => {
+                let reference = input.strip_prefix('&')?;
+                let end = reference.find(';').filter(|&end| end <= 8)?;
+                let name = reference[..end].to_ascii_lowercase();
+                let symbol = match name.as_str() {
+                    "commat" => '@',
+                    "period" => '.',
+                    _ => {
+                        let code = name.strip_prefix('#')?;
+                        let code = match code.strip_prefix('x') {
+                            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                            None => code.parse().ok()?,
+                        };
+                        char::from_u32(code).filter(|&symbol| symbol == '@' || symbol == '.')?
+                    }
+                };
+                Some((end + 2, symbol))
+            }
+            Obfuscation::SpacedSymbol => {
+                let at = skip_white_space(input, 0);
+                let symbol = input[at..].chars().next().filter(|&symbol| symbol == '@' || symbol == '.')?;
+                let end = skip_white_space(input, at + 1);
+                (is_inner && (at > 0 || end > at + 1) && end < input.len()).then_some((end, symbol))
+            }
+        }
+    }
+}
+
+/// Matches `at` or `dot` at `start`, case-insensitively; returns the end and the symbol.
+fn keyword(input: &str, start: usize) -> Option<(usize, char)> {
+    let word_end = input[start..]
+        .find(|ch: char| !ch.is_ascii_alphabetic())
+        .map_or(input.len(), |end| start + end);
+    let word = &input[start..word_end];
+    if word.eq_ignore_ascii_case("at") {
+        Some((word_end, '@'))
+    } else if word.eq_ignore_ascii_case("dot") {
+        Some((word_end, '.'))
+    } else {
+        None
+    }
+}
+
+fn skip_white_space(input: &str, start: usize) -> usize {
+    input[start..]
+        .find(|ch: char| !ch.is_whitespace())
+        .map_or(input.len(), |end| start + end)
+}
+
+/// A match of an [`Obfuscation`] that was replaced by the symbol it stands for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Substitution {
+    /// The pattern that matched.
+    pub obfuscation: Obfuscation,
+    /// The byte range of the match in the input, including the white space around it.
+    pub range: Range<usize>,
+    /// The symbol that replaced the match; `@` or `.`.
+    pub symbol: char,
+}
+
+/// A valid address recovered by [`Deobfuscator::deobfuscate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deobfuscated {
+    /// The recovered address.
+    pub email: EmailAddress,
+    /// How likely it is that the address is the one that was obfuscated, from 0 to 1.
+    pub confidence: f64,
+    /// The substitutions that were applied to the input, in order.
+    pub substitutions: Vec<Substitution>,
+}
+
+/// Recovers addresses from obfuscated input, such as `john [at] example [dot] com`.
+///
+/// Every match of the enabled patterns may stand for an `@` or a `.`; the candidates applying
+/// some or all of them are validated with [`EmailAddress::parse`]. The confidence of a
+/// candidate is the product of the [`Obfuscation::confidence`] of its substitutions, halved for
+/// every match left as it is.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let deobfuscator = Deobfuscator::new();
+/// let candidates = deobfuscator.deobfuscate("john [at] example [dot] com", None);
+/// assert_eq!(candidates[0].email, "john@example.com");
+/// assert_eq!(candidates[0].substitutions.len(), 2);
+///
+/// let deobfuscator = Deobfuscator { spelled_words: false, ..Deobfuscator::new() };
+/// assert!(deobfuscator.deobfuscate("john at example dot com", None).is_empty());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Deobfuscator {
+    /// Recognises [`Obfuscation::BracketedWord`].
+    pub bracketed_words: bool,
+    /// Recognises [`Obfuscation::SpelledWord`].
+    pub spelled_words: bool,
+    /// Recognises [`Obfuscation::HtmlEntity`].
+    pub html_entities: bool,
+    /// Recognises [`Obfuscation::SpacedSymbol`].
+    pub spaced_symbols: bool,
+}
+
+impl Deobfuscator {
+    /// Instantiates a deobfuscator recognising every pattern.
+    pub fn new() -> Deobfuscator {
+        Deobfuscator {
+            bracketed_words: true,
+            spelled_words: true,
+            html_entities: true,
+            spaced_symbols: true,
+        }
+    }
+
+    /// Returns the enabled patterns, in the order they are tried at every position.
+    fn obfuscations(&self) -> Vec<Obfuscation> {
+        [
+            (self.html_entities, Obfuscation::HtmlEntity),
+            (self.bracketed_words, Obfuscation::BracketedWord),
+            (self.spelled_words, Obfuscation::SpelledWord),
+            (self.spaced_symbols, Obfuscation::SpacedSymbol),
+        ]
+        .iter()
+        .filter_map(|&(is_enabled, obfuscation)| is_enabled.then_some(obfuscation))
+        .collect()
+    }
+
+    /// Returns the valid addresses that `input` may stand for, most likely first.
+    ///
+    /// `input` is a single, possibly obfuscated, address; white space around it is ignored.
+    /// An input that is a valid address as it is yields itself, with a confidence of 1 if none of the
+    /// patterns matches it; every match left as it is halves the confidence, so `john @example.com`
+    /// yields `john@example.com` first, and itself with a confidence of 0.5.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let candidates = Deobfuscator::new().deobfuscate(" john(at)example&#46;com ", None);
+    /// assert_eq!(candidates.len(), 1);
+    /// assert_eq!(candidates[0].email, "john@example.com");
+    /// let obfuscations: Vec<Obfuscation> = candidates[0].substitutions.iter().map(|s| s.obfuscation).collect();
+    /// assert_eq!(obfuscations, vec![Obfuscation::BracketedWord, Obfuscation::HtmlEntity]);
+    /// assert_eq!(candidates[0].substitutions[0].range, 5..9);
+    /// ```
+    pub fn deobfuscate(&self, input: &str, options: Option<ParsingOptions>) -> Vec<Deobfuscated> {
+        let start = input.len() - input.trim_start().len();
+        let trimmed = input.trim();
+        let substitutions = self.find_substitutions(trimmed, start);
+        let masks: Vec<u32> = if substitutions.len() > MAX_OPTIONAL_SUBSTITUTIONS {
+            Vec::from([u32::MAX])
+        } else {
+            (0..1 << substitutions.len()).rev().collect()
+        };
+
+        let mut candidates: Vec<Deobfuscated> = Vec::new();
+        for mask in masks {
+            let mut candidate = String::with_capacity(trimmed.len());
+            let mut applied = Vec::new();
+            let mut confidence = 1.0;
+            let mut end = start;
+            for (index, substitution) in substitutions.iter().enumerate() {
+                if mask >> index.min(31) & 1 == 0 {
+                    confidence *= SKIPPED_PENALTY;
+                    continue;
+                }
+                candidate.push_str(&input[end..substitution.range.start]);
+                candidate.push(substitution.symbol);
+                confidence *= substitution.obfuscation.confidence();
+                end = substitution.range.end;
+                applied.push(substitution.clone());
+            }
+            candidate.push_str(&input[end..start + trimmed.len()]);
+            let Some(email) = EmailAddress::parse(&candidate, options.clone()) else {
+                continue;
+            };
+            if candidates.iter().all(|existing| existing.email != email) {
+                candidates.push(Deobfuscated {
+                    email,
+                    confidence,
+                    substitutions: applied,
+                });
+            }
+        }
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        candidates
+    }
+
+    /// Finds the matches of the enabled patterns in `input`, from left to right, without overlaps
+    /// and outside quoted strings; `offset` is added to their ranges.
+    fn find_substitutions(&self, input: &str, offset: usize) -> Vec<Substitution> {
+        let obfuscations = self.obfuscations();
+        let mut substitutions = Vec::new();
+        let (mut index, mut is_quoted) = (0, false);
+        while let Some(ch) = input[index..].chars().next() {
+            if ch == '"' || is_quoted {
+                is_quoted ^= ch == '"';
+                index += ch.len_utf8();
+                if is_quoted && ch == '\\' {
+                    index += input[index..].chars().next().map_or(0, char::len_utf8);
+                }
+                continue;
+            }
+            let found = obfuscations.iter().find_map(|obfuscation| {
+                let (len, symbol) = obfuscation.match_at(&input[index..], index > 0)?;
+                Some(Substitution {
+                    obfuscation: *obfuscation,
+                    range: offset + index..offset + index + len,
+                    symbol,
+                })
+            });
+            match found {
+                Some(substitution) => {
+                    index = substitution.range.end - offset;
+                    substitutions.push(substitution);
+                }
+                None => index += ch.len_utf8(),
+            }
+        }
+        substitutions
+    }
+}
+
+impl Default for Deobfuscator {
+    fn default() -> Self {
+        Deobfuscator::new()
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Recovers the most likely address from obfuscated input, recognising every pattern of
+    /// [`Obfuscation`]; use [`Deobfuscator`] to choose the patterns, or to get every candidate.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let deobfuscated = EmailAddress::deobfuscate("john at example dot com", None).unwrap();
+    /// assert_eq!(deobfuscated.email, "john@example.com");
+    /// assert!(deobfuscated.confidence < 1.0);
+    ///
+    /// assert!(EmailAddress::deobfuscate("meet me at the station", None).is_none());
+    /// ```
+    pub fn deobfuscate(input: &str, options: Option<ParsingOptions>) -> Option<Deobfuscated> {
+        Deobfuscator::new().deobfuscate(input, options).into_iter().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deobfuscate(input: &str) -> Vec<(String, Vec<Obfuscation>)> {
+        Deobfuscator::new()
+            .deobfuscate(input, None)
+            .into_iter()
+            .map(|candidate| {
+                let obfuscations = candidate.substitutions.iter().map(|s| s.obfuscation).collect();
+                (candidate.email.to_string(), obfuscations)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn recognises_every_pattern() {
+        use Obfuscation::*;
+        let cases = [
+            ("john [at] example [dot] com", vec![BracketedWord, BracketedWord]),
+            ("john[AT]example[DOT]com", vec![BracketedWord, BracketedWord]),
+            ("john(at)example.com", vec![BracketedWord]),
+            ("john { at } example { dot } com", vec![BracketedWord, BracketedWord]),
+            ("john at example dot com", vec![SpelledWord, SpelledWord]),
+            ("john AT example.com", vec![SpelledWord]),
+            ("john&#64;example.com", vec![HtmlEntity]),
+            ("john&#x40;example&#X2e;com", vec![HtmlEntity, HtmlEntity]),
+            ("john&#064;example&period;com", vec![HtmlEntity, HtmlEntity]),
+            ("john&commat;example.com", vec![HtmlEntity]),
+            ("john @ example . com", vec![SpacedSymbol, SpacedSymbol]),
+            ("john\u{a0}@\u{a0}example.com", vec![SpacedSymbol]),
+            ("john [at] example dot com", vec![BracketedWord, SpelledWord]),
+        ];
+        for (input, obfuscations) in cases {
+            let best = deobfuscate(input).into_iter().next();
+            assert_eq!(best, Some((String::from("john@example.com"), obfuscations)), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn ranks_candidates_and_skips_matches() {
+        let candidates = Deobfuscator::new().deobfuscate("john.at.home@example.com", None);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!((candidates[0].confidence, candidates[0].substitutions.len()), (1.0, 0));
+
+        let candidates = Deobfuscator::new().deobfuscate("\"meet at noon\"@example.com", None);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!((candidates[0].confidence, candidates[0].substitutions.len()), (1.0, 0));
+
+        // White space before the `@` of a dot-atom is valid, so the match may be left as it is.
+        let candidates = Deobfuscator::new().deobfuscate("john @example.com", None);
+        let emails: Vec<&str> = candidates.iter().map(|candidate| candidate.email.as_str()).collect();
+        assert_eq!(emails, vec!["john@example.com", "john @example.com"]);
+        assert_eq!(candidates[1].confidence, SKIPPED_PENALTY);
+
+        let candidates = Deobfuscator::new().deobfuscate("at at example dot com", None);
+        assert_eq!(candidates[0].email, "at@example.com");
+    }
+
+    #[test]
+    fn rejects_what_is_not_an_address() {
+        for input in ["", "at", "[at]", "john at", "john at example dot", "meet me at the station", "a&#65;b"] {
+            assert_eq!(deobfuscate(input), vec![], "{:?}", input);
+        }
+        let deobfuscator = Deobfuscator {
+            html_entities: false,
+            ..Deobfuscator::new()
+        };
+        assert!(deobfuscator.deobfuscate("john&#64;example.com", None).is_empty());
+    }
+}
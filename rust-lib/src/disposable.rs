@@ -0,0 +1,242 @@
+//! Detection of disposable (throwaway) mailbox domains, such as `mailinator.com`.
+use crate::cfws::strip_cfws;
+use crate::email_address::EmailAddress;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::{fs, io, path::Path};
+
+/// The bundled domains: lowercase, sorted, one per line, so that they can be searched in place.
+const BUNDLED: &str = include_str!("../data/disposable_domains.txt");
+
+/// Returns `true` if `BUNDLED` has a line equal to `domain`, by binary search over its bytes.
+fn is_bundled(domain: &str) -> bool {
+    let (mut low, mut high) = (0, BUNDLED.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let start = BUNDLED[..middle].rfind('\n').map_or(0, |newline| newline + 1);
+        let end = BUNDLED[start..].find('\n').map_or(BUNDLED.len(), |newline| start + newline);
+        match BUNDLED[start..end].cmp(domain) {
+            core::cmp::Ordering::Equal => return true,
+            core::cmp::Ordering::Less => low = end + 1,
+            core::cmp::Ordering::Greater => high = start,
+        }
+    }
+    false
+}
+
+/// Calls `contains` with `domain`, lowercase and without a trailing dot, then with every domain it is a subdomain of.
+fn matches_suffix(domain: &str, contains: impl Fn(&str) -> bool) -> bool {
+    if domain.starts_with('[') {
+        return false;
+    }
+    let domain = domain.trim_end_matches('.').to_lowercase();
+    let mut suffix = domain.as_str();
+    loop {
+        if contains(suffix) {
+            return true;
+        }
+        match suffix.find('.') {
+            Some(dot) => suffix = &suffix[dot + 1..],
+            None => return false,
+        }
+    }
+}
+
+/// A set of disposable domains, e.g. loaded from a list that is updated more often than the crate.
+///
+/// A domain is disposable if it, or any domain it is a subdomain of, is in the set; e.g. both
+/// `mailinator.com` and `eu.mailinator.com` are when the set contains `mailinator.com`.
+/// Domains are compared case-insensitively.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let mut domains = DisposableDomains::bundled();
+/// domains.extend_from_list("# added by us\nthrowaway.example\n");
+/// let email = EmailAddress::parse("bot@mx.Throwaway.example", None).unwrap();
+/// assert!(domains.is_disposable(&email));
+/// assert!(!email.is_disposable());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisposableDomains {
+    domains: BTreeSet<String>,
+}
+
+impl DisposableDomains {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Instantiates an empty set.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisposableDomains;
+    ///
+    /// assert!(!DisposableDomains::new().contains("mailinator.com"));
+    /// ```
+    pub fn new() -> DisposableDomains {
+        DisposableDomains::default()
+    }
+
+    /// Instantiates a set with the domains bundled with the crate, which `EmailAddress::is_disposable` uses.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisposableDomains;
+    ///
+    /// assert!(DisposableDomains::bundled().contains("mailinator.com"));
+    /// ```
+    pub fn bundled() -> DisposableDomains {
+        let mut domains = DisposableDomains::new();
+        domains.extend_from_list(BUNDLED);
+        domains
+    }
+
+    /// Loads a set from a list file; see [`DisposableDomains::extend_from_list`] for its format.
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use email_address_parser::DisposableDomains;
+    ///
+    /// let domains = DisposableDomains::load("/etc/disposable_domains.txt").unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<DisposableDomains> {
+        let mut domains = DisposableDomains::new();
+        domains.extend_from_list(&fs::read_to_string(path)?);
+        Ok(domains)
+    }
+
+    /// Adds a domain to the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisposableDomains;
+    ///
+    /// let mut domains = DisposableDomains::new();
+    /// domains.insert("Throwaway.example.");
+    /// assert!(domains.contains("throwaway.example"));
+    /// ```
+    pub fn insert(&mut self, domain: &str) {
+        self.domains.insert(domain.trim_end_matches('.').to_lowercase());
+    }
+
+    /// Adds the domains of a list, one per line; blank lines and lines starting with `#` are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisposableDomains;
+    ///
+    /// let mut domains = DisposableDomains::new();
+    /// domains.extend_from_list("# throwaway domains\r\na.example\n\n  b.example  \n");
+    /// assert!(domains.contains("a.example") && domains.contains("b.example"));
+    /// ```
+    pub fn extend_from_list(&mut self, list: &str) {
+        list.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .for_each(|domain| self.insert(domain));
+    }
+
+    /// Returns `true` if `domain`, or a domain it is a subdomain of, is in the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisposableDomains;
+    ///
+    /// let domains = DisposableDomains::bundled();
+    /// assert!(domains.contains("eu.mailinator.com"));
+    /// assert!(!domains.contains("notmailinator.example"));
+    /// ```
+    pub fn contains(&self, domain: &str) -> bool {
+        matches_suffix(domain, |suffix| self.domains.contains(suffix))
+    }
+
+    /// Returns `true` if the domain of `email`, without comments and white space, is in the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("bot@yopmail.com", None).unwrap();
+    /// assert!(DisposableDomains::bundled().is_disposable(&email));
+    /// ```
+    pub fn is_disposable(&self, email: &EmailAddress) -> bool {
+        self.contains(&strip_cfws(email.get_domain()))
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Returns `true` if the domain is a known disposable one, or a subdomain of one, according to
+    /// the list bundled with the crate; use [`DisposableDomains`] for other lists.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddress;
+    ///
+    /// assert!(EmailAddress::parse("bot@mailinator.com", None).unwrap().is_disposable());
+    /// assert!(EmailAddress::parse("bot@eu.Mailinator.com", None).unwrap().is_disposable());
+    /// assert!(!EmailAddress::parse("jane@example.com", None).unwrap().is_disposable());
+    /// ```
+    pub fn is_disposable(&self) -> bool {
+        matches_suffix(&strip_cfws(self.get_domain()), is_bundled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn bundled_list_is_sorted_lowercase_and_unique() {
+        let lines: Vec<&str> = BUNDLED.lines().collect();
+        assert!(BUNDLED.ends_with('\n') && BUNDLED.is_ascii());
+        for pair in lines.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} must come before {:?}", pair[1], pair[0]);
+        }
+        for line in &lines {
+            assert_eq!(line.to_lowercase(), *line);
+            assert!(EmailAddress::new("a", line, None).is_ok(), "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn searches_the_bundled_list_in_place() {
+        let bundled = DisposableDomains::bundled();
+        for line in BUNDLED.lines() {
+            assert!(is_bundled(line), "{:?}", line);
+            assert!(bundled.contains(line));
+        }
+        for domain in ["", "com", "a", "zzzz.com", "mailinator.co", "mailinator.comm", "example.com"] {
+            assert!(!is_bundled(domain), "{:?}", domain);
+            assert!(!bundled.contains(domain), "{:?}", domain);
+        }
+    }
+
+    #[test]
+    fn matches_subdomains_only() {
+        let is_disposable = |address| EmailAddress::parse(address, Some(crate::ParsingOptions::new(true))).unwrap().is_disposable();
+        assert!(is_disposable("a@MAILINATOR.COM"));
+        assert!(is_disposable("a@x.y.mailinator.com"));
+        assert!(!is_disposable("a@xmailinator.com"));
+        assert!(!is_disposable("a@[127.0.0.1]"));
+        assert!(!is_disposable("a@com"));
+    }
+
+    #[test]
+    fn ignores_comments_and_white_space() {
+        let parse = |address| EmailAddress::parse(address, Some(crate::ParsingOptions::new(true))).unwrap();
+        for address in ["bot@mailinator.com ", "bot@(x)mailinator.com", "bot@ (x) eu . mailinator.com"] {
+            assert!(parse(address).is_disposable(), "{:?}", address);
+            assert!(DisposableDomains::bundled().is_disposable(&parse(address)), "{:?}", address);
+        }
+    }
+}
@@ -0,0 +1,442 @@
+//! Checks whether the domain of an address can receive mail, with pluggable DNS resolvers.
+//!
+//! [`check_deliverability`] looks up the MX records of the domain, and falls back to its A and
+//! AAAA records as [RFC 5321](https://tools.ietf.org/html/rfc5321#section-5.1) does; the
+//! outcome corresponds to the `ISEMAIL_DNSWARN_NO_MX_RECORD` and `ISEMAIL_DNSWARN_NO_RECORD`
+//! diagnoses of isemail. Lookups go through a [`Resolver`]: [`MockResolver`] answers from memory,
+//! e.g. in tests, and `SystemResolver` (with the `system-resolver` feature) asks the resolvers
+//! configured for the system.
+//!
+//! # Examples
+//! ```
+//! use email_address_parser::dns::*;
+//! use email_address_parser::EmailAddress;
+//!
+//! let mut resolver = MockResolver::new();
+//! resolver.add_mx("example.com", 10, "mx.example.com");
+//! let email = EmailAddress::parse("jane@example.com", None).unwrap();
+//! assert!(check_deliverability(&email, &resolver).is_deliverable());
+//! ```
+use crate::cfws::strip_cfws;
+use crate::email_address::EmailAddress;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::net::IpAddr;
+
+/// An MX record, naming a host that accepts mail for a domain.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MxRecord {
+    /// The preference of the host; lower is tried first.
+    pub preference: u16,
+    /// The domain name of the host; `.` in a null MX record.
+    pub exchange: String,
+}
+
+impl MxRecord {
+    /// Returns `true` if the record is a null MX record of
+    /// [RFC 7505](https://tools.ietf.org/html/rfc7505), whose exchange is the root domain.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::MxRecord;
+    ///
+    /// assert!(MxRecord { preference: 0, exchange: String::from(".") }.is_null());
+    /// assert!(!MxRecord { preference: 0, exchange: String::from("mx.example.com") }.is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        self.exchange.trim_end_matches('.').is_empty()
+    }
+}
+
+/// The reason a lookup did not answer; a domain without records of the type is not an error.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LookupError {
+    /// The domain does not exist (`NXDOMAIN`).
+    NoSuchDomain,
+    /// The lookup failed, e.g. timed out or was refused, with a description of the failure.
+    Failed(String),
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            LookupError::NoSuchDomain => write!(formatter, "no such domain"),
+            LookupError::Failed(message) => write!(formatter, "lookup failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+/// Looks up the DNS records that [`check_deliverability`] needs.
+///
+/// Domains are passed as they appear in addresses, without a trailing dot, possibly with
+/// Unicode characters; implementations convert them to the form their DNS library expects.
+/// Every method returns an empty list for a domain that exists without records of the type.
+pub trait Resolver {
+    /// Returns the MX records of `domain`.
+    fn lookup_mx(&self, domain: &str) -> Result<Vec<MxRecord>, LookupError>;
+
+    /// Returns the addresses of the A and AAAA records of `domain`.
+    fn lookup_ip(&self, domain: &str) -> Result<Vec<IpAddr>, LookupError>;
+}
+
+impl<R: Resolver + ?Sized> Resolver for &R {
+    fn lookup_mx(&self, domain: &str) -> Result<Vec<MxRecord>, LookupError> {
+        (**self).lookup_mx(domain)
+    }
+
+    fn lookup_ip(&self, domain: &str) -> Result<Vec<IpAddr>, LookupError> {
+        (**self).lookup_ip(domain)
+    }
+}
+
+/// Whether the domain of an address accepts mail, returned by [`check_deliverability`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Deliverability {
+    /// The domain has MX records, sorted by preference; null MX records among others are dropped.
+    Mx(Vec<MxRecord>),
+    /// The domain has no MX records, so mail goes to the addresses of its A or AAAA records;
+    /// isemail warns about this with `ISEMAIL_DNSWARN_NO_MX_RECORD`.
+    ImplicitMx(Vec<IpAddr>),
+    /// The domain is a domain literal, such as `[127.0.0.1]`, so no lookup was needed.
+    DomainLiteral,
+    /// The domain declares that it accepts no mail, with a null MX record of RFC 7505.
+    NullMx,
+    /// The domain exists, but has neither MX, A, nor AAAA records; isemail diagnoses this as
+    /// `ISEMAIL_DNSWARN_NO_RECORD`.
+    NoRecords,
+    /// The domain does not exist.
+    NoSuchDomain,
+    /// A lookup failed, so deliverability is unknown.
+    LookupFailed(String),
+}
+
+impl Deliverability {
+    /// Returns `true` if mail can be sent to the domain.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::Deliverability;
+    ///
+    /// assert!(Deliverability::ImplicitMx(vec!["192.0.2.1".parse().unwrap()]).is_deliverable());
+    /// assert!(!Deliverability::NullMx.is_deliverable());
+    /// ```
+    pub fn is_deliverable(&self) -> bool {
+        matches!(
+            self,
+            Deliverability::Mx(_) | Deliverability::ImplicitMx(_) | Deliverability::DomainLiteral
+        )
+    }
+}
+
+/// Checks whether the domain of `email` accepts mail, by looking up its MX records, then its A
+/// and AAAA records if it has none.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::dns::*;
+/// use email_address_parser::EmailAddress;
+///
+/// let mut resolver = MockResolver::new();
+/// resolver.add_mx("example.com", 0, ".");
+/// resolver.add_address("example.org", "192.0.2.1".parse().unwrap());
+///
+/// let email = EmailAddress::parse("jane@example.com", None).unwrap();
+/// assert_eq!(check_deliverability(&email, &resolver), Deliverability::NullMx);
+/// let email = EmailAddress::parse("jane@example.org", None).unwrap();
+/// assert!(matches!(check_deliverability(&email, &resolver), Deliverability::ImplicitMx(_)));
+/// let email = EmailAddress::parse("jane@example.net", None).unwrap();
+/// assert_eq!(check_deliverability(&email, &resolver), Deliverability::NoSuchDomain);
+/// ```
+pub fn check_deliverability(email: &EmailAddress, resolver: &impl Resolver) -> Deliverability {
+    let domain = strip_cfws(email.get_domain());
+    if domain.starts_with('[') {
+        return Deliverability::DomainLiteral;
+    }
+    let domain = domain.trim_end_matches('.');
+    match resolver.lookup_mx(domain) {
+        Ok(records) if !records.is_empty() && records.iter().all(MxRecord::is_null) => return Deliverability::NullMx,
+        Ok(mut records) if !records.is_empty() => {
+            records.retain(|record| !record.is_null());
+            records.sort_by_key(|record| record.preference);
+            return Deliverability::Mx(records);
+        }
+        Ok(_) => {}
+        Err(LookupError::NoSuchDomain) => return Deliverability::NoSuchDomain,
+        Err(LookupError::Failed(message)) => return Deliverability::LookupFailed(message),
+    }
+    match resolver.lookup_ip(domain) {
+        Ok(addresses) if addresses.is_empty() => Deliverability::NoRecords,
+        Ok(addresses) => Deliverability::ImplicitMx(addresses),
+        Err(LookupError::NoSuchDomain) => Deliverability::NoSuchDomain,
+        Err(LookupError::Failed(message)) => Deliverability::LookupFailed(message),
+    }
+}
+
+/// A [`Resolver`] answering from records added to it; domains without records do not exist.
+///
+/// Domains are compared case-insensitively, with or without a trailing dot.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::dns::*;
+/// use email_address_parser::EmailAddress;
+///
+/// let mut resolver = MockResolver::new();
+/// resolver.add_domain("example.com");
+/// resolver.add_failure("example.org");
+///
+/// let email = EmailAddress::parse("jane@Example.com", None).unwrap();
+/// assert_eq!(check_deliverability(&email, &resolver), Deliverability::NoRecords);
+/// let email = EmailAddress::parse("jane@example.org", None).unwrap();
+/// assert!(matches!(check_deliverability(&email, &resolver), Deliverability::LookupFailed(_)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockResolver {
+    domains: BTreeSet<String>,
+    mx_records: BTreeMap<String, Vec<MxRecord>>,
+    addresses: BTreeMap<String, Vec<IpAddr>>,
+    failures: BTreeSet<String>,
+}
+
+impl MockResolver {
+    /// Instantiates a resolver without records.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::*;
+    ///
+    /// assert_eq!(MockResolver::new().lookup_mx("example.com"), Err(LookupError::NoSuchDomain));
+    /// ```
+    pub fn new() -> MockResolver {
+        MockResolver::default()
+    }
+
+    /// Adds a domain without records.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::*;
+    ///
+    /// let mut resolver = MockResolver::new();
+    /// resolver.add_domain("example.com");
+    /// assert_eq!(resolver.lookup_mx("example.com"), Ok(vec![]));
+    /// ```
+    pub fn add_domain(&mut self, domain: &str) {
+        self.domains.insert(normalize(domain));
+    }
+
+    /// Adds an MX record to `domain`; an `exchange` of `.` makes a null MX record.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::*;
+    ///
+    /// let mut resolver = MockResolver::new();
+    /// resolver.add_mx("example.com", 10, "mx.example.com");
+    /// assert_eq!(resolver.lookup_mx("example.com").unwrap()[0].exchange, "mx.example.com");
+    /// ```
+    pub fn add_mx(&mut self, domain: &str, preference: u16, exchange: &str) {
+        self.add_domain(domain);
+        let record = MxRecord {
+            preference,
+            exchange: String::from(exchange),
+        };
+        self.mx_records.entry(normalize(domain)).or_default().push(record);
+    }
+
+    /// Adds an A or AAAA record to `domain`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::*;
+    ///
+    /// let mut resolver = MockResolver::new();
+    /// resolver.add_address("example.com", "2001:db8::1".parse().unwrap());
+    /// assert_eq!(resolver.lookup_ip("example.com").unwrap().len(), 1);
+    /// ```
+    pub fn add_address(&mut self, domain: &str, address: IpAddr) {
+        self.add_domain(domain);
+        self.addresses.entry(normalize(domain)).or_default().push(address);
+    }
+
+    /// Makes every lookup of `domain` fail.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::dns::*;
+    ///
+    /// let mut resolver = MockResolver::new();
+    /// resolver.add_failure("example.com");
+    /// assert!(matches!(resolver.lookup_ip("example.com"), Err(LookupError::Failed(_))));
+    /// ```
+    pub fn add_failure(&mut self, domain: &str) {
+        self.failures.insert(normalize(domain));
+    }
+
+    fn lookup<T: Clone>(&self, records: &BTreeMap<String, Vec<T>>, domain: &str) -> Result<Vec<T>, LookupError> {
+        let domain = normalize(domain);
+        if self.failures.contains(&domain) {
+            return Err(LookupError::Failed(format!("{} is set to fail", domain)));
+        }
+        if !self.domains.contains(&domain) {
+            return Err(LookupError::NoSuchDomain);
+        }
+        Ok(records.get(&domain).cloned().unwrap_or_default())
+    }
+}
+
+impl Resolver for MockResolver {
+    fn lookup_mx(&self, domain: &str) -> Result<Vec<MxRecord>, LookupError> {
+        self.lookup(&self.mx_records, domain)
+    }
+
+    fn lookup_ip(&self, domain: &str) -> Result<Vec<IpAddr>, LookupError> {
+        self.lookup(&self.addresses, domain)
+    }
+}
+
+/// A [`Resolver`] asking the resolvers configured for the system, e.g. in `/etc/resolv.conf`.
+///
+/// Lookups block the calling thread. Not accessible from WASM.
+///
+/// # Examples
+/// ```no_run
+/// use email_address_parser::dns::*;
+/// use email_address_parser::EmailAddress;
+///
+/// let resolver = SystemResolver::new().unwrap();
+/// let email = EmailAddress::parse("jane@example.com", None).unwrap();
+/// println!("{:?}", check_deliverability(&email, &resolver));
+/// ```
+#[cfg(feature = "system-resolver")]
+pub struct SystemResolver {
+    resolver: hickory_resolver::Resolver,
+}
+
+#[cfg(feature = "system-resolver")]
+impl SystemResolver {
+    /// Instantiates a resolver with the system configuration.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use email_address_parser::dns::SystemResolver;
+    ///
+    /// let resolver = SystemResolver::new().unwrap();
+    /// ```
+    pub fn new() -> std::io::Result<SystemResolver> {
+        Ok(SystemResolver {
+            resolver: hickory_resolver::Resolver::from_system_conf()?,
+        })
+    }
+
+    /// Returns the domain as a fully qualified name, so that search domains are not tried.
+    fn name(domain: &str) -> Result<hickory_resolver::Name, LookupError> {
+        hickory_resolver::Name::from_utf8(format!("{}.", domain)).map_err(|error| LookupError::Failed(error.to_string()))
+    }
+
+    fn lookup_error(error: hickory_resolver::error::ResolveError) -> Result<(), LookupError> {
+        use hickory_resolver::error::ResolveErrorKind;
+        use hickory_resolver::proto::op::ResponseCode;
+        match error.kind() {
+            ResolveErrorKind::NoRecordsFound {
+                response_code: ResponseCode::NXDomain,
+                ..
+            } => Err(LookupError::NoSuchDomain),
+            ResolveErrorKind::NoRecordsFound { .. } => Ok(()),
+            _ => Err(LookupError::Failed(error.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "system-resolver")]
+impl Resolver for SystemResolver {
+    fn lookup_mx(&self, domain: &str) -> Result<Vec<MxRecord>, LookupError> {
+        match self.resolver.mx_lookup(SystemResolver::name(domain)?) {
+            Ok(lookup) => Ok(lookup
+                .iter()
+                .map(|mx| MxRecord {
+                    preference: mx.preference(),
+                    exchange: mx.exchange().to_utf8(),
+                })
+                .collect()),
+            Err(error) => SystemResolver::lookup_error(error).map(|_| Vec::new()),
+        }
+    }
+
+    fn lookup_ip(&self, domain: &str) -> Result<Vec<IpAddr>, LookupError> {
+        match self.resolver.lookup_ip(SystemResolver::name(domain)?) {
+            Ok(lookup) => Ok(lookup.iter().collect()),
+            Err(error) => SystemResolver::lookup_error(error).map(|_| Vec::new()),
+        }
+    }
+}
+
+/// Returns `domain` lowercase, without a trailing dot.
+fn normalize(domain: &str) -> String {
+    domain.trim_end_matches('.').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsingOptions;
+
+    fn check(address: &str, resolver: &MockResolver) -> Deliverability {
+        let email = EmailAddress::parse(address, Some(ParsingOptions::new(true))).unwrap();
+        check_deliverability(&email, resolver)
+    }
+
+    #[test]
+    fn prefers_mx_records() {
+        let mut resolver = MockResolver::new();
+        resolver.add_mx("example.com", 20, "backup.example.com");
+        resolver.add_mx("example.com", 10, "mx.example.com");
+        resolver.add_address("example.com", "192.0.2.1".parse().unwrap());
+        match check("a@EXAMPLE.com", &resolver) {
+            Deliverability::Mx(records) => {
+                let exchanges: Vec<&str> = records.iter().map(|record| record.exchange.as_str()).collect();
+                assert_eq!(exchanges, ["mx.example.com", "backup.example.com"]);
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(check("a@(comment)example.com", &resolver), Deliverability::Mx(_)));
+    }
+
+    #[test]
+    fn falls_back_to_address_records() {
+        let mut resolver = MockResolver::new();
+        resolver.add_address("example.com", "2001:db8::1".parse().unwrap());
+        resolver.add_domain("example.org");
+        let expected = Deliverability::ImplicitMx(vec!["2001:db8::1".parse().unwrap()]);
+        assert_eq!(check("a@example.com", &resolver), expected);
+        assert_eq!(check("a@example.org", &resolver), Deliverability::NoRecords);
+        assert_eq!(check("a@example.net", &resolver), Deliverability::NoSuchDomain);
+        assert_eq!(check("a@[192.0.2.1]", &resolver), Deliverability::DomainLiteral);
+    }
+
+    #[test]
+    fn recognizes_null_mx_records() {
+        let mut resolver = MockResolver::new();
+        resolver.add_mx("example.com", 0, ".");
+        resolver.add_address("example.com", "192.0.2.1".parse().unwrap());
+        resolver.add_mx("example.org", 0, "");
+        resolver.add_mx("example.org", 10, "mx.example.org");
+        assert_eq!(check("a@example.com", &resolver), Deliverability::NullMx);
+        assert!(!Deliverability::NullMx.is_deliverable());
+        match check("a@example.org", &resolver) {
+            Deliverability::Mx(records) => assert_eq!(records.len(), 1),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn reports_failed_lookups() {
+        let mut resolver = MockResolver::new();
+        resolver.add_failure("example.com");
+        let result = check("a@example.com", &resolver);
+        assert_eq!(result, Deliverability::LookupFailed(String::from("example.com is set to fail")));
+        assert!(!result.is_deliverable());
+    }
+}
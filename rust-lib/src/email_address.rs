@@ -1,10 +1,15 @@
 use crate::nom_parser;
-#[cfg(target_arch = "wasm32")]
-extern crate console_error_panic_hook;
-use std::fmt;
-use std::hash::Hash;
-use std::str::FromStr;
-#[cfg(target_arch = "wasm32")]
+use crate::parse_error::{ParseError, ParseErrorKind};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+#[cfg(all(target_arch = "wasm32", feature = "std"))]
 use wasm_bindgen::prelude::*;
 
 /// Options for parsing.
@@ -12,15 +17,13 @@ use wasm_bindgen::prelude::*;
 /// There is only one available option so far `is_lax` which can be set to
 /// `true` or `false` to  enable/disable obsolete parts parsing.
 /// The default is `false`.
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[cfg_attr(all(target_arch = "wasm32", feature = "std"), wasm_bindgen)]
 #[derive(Debug,Clone)]
 pub struct ParsingOptions {
     pub is_lax: bool,
 }
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl ParsingOptions {
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
     pub fn new(is_lax: bool) -> ParsingOptions {
         ParsingOptions { is_lax }
     }
@@ -62,6 +65,12 @@ impl FromStr for EmailAddress {
 
 /// Email address struct.
 ///
+/// The whole address is stored in a single buffer together with the offset of
+/// the `@` separating the local part from the domain.
+/// Equality, ordering and hashing are those of the whole address string
+/// (see [`EmailAddress::as_str`]), so an `EmailAddress` can be looked up by `&str`
+/// in hash- and tree-based collections.
+///
 /// # Examples
 /// ```
 /// use email_address_parser::EmailAddress;
@@ -74,48 +83,17 @@ impl FromStr for EmailAddress {
 /// assert_eq!(email.get_domain(), "bar.com");
 /// assert_eq!(format!("{}", email), "foo@bar.com");
 /// ```
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(all(target_arch = "wasm32", feature = "std"), wasm_bindgen)]
+#[derive(Clone, Debug)]
 pub struct EmailAddress {
-    local_part: String,
-    domain: String,
+    address: String,
+    at: usize,
 }
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl EmailAddress {
     #![warn(missing_docs)]
     #![warn(rustdoc::missing_doc_code_examples)]
 
-    /// This is a WASM wrapper over EmailAddress::new that panics.
-    /// If you are using this lib from Rust then consider using EmailAddress::new.
-    ///
-    /// # Examples
-    /// ```
-    /// use email_address_parser::EmailAddress;
-    ///
-    /// let email = EmailAddress::_new("foo", "bar.com", None);
-    /// ```
-    ///
-    /// # Panics
-    ///
-    /// This method panics if the local part or domain is invalid.
-    ///
-    /// ```rust,should_panic,ignore-wasm32
-    /// use email_address_parser::EmailAddress;
-    ///
-    /// EmailAddress::_new("foo", "-bar.com", None);
-    /// ```
-    #[doc(hidden)]
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
-    pub fn _new(local_part: &str, domain: &str, options: Option<ParsingOptions>) -> EmailAddress {
-        #[cfg(target_arch = "wasm32")]
-        console_error_panic_hook::set_once();
-        match EmailAddress::new(local_part, domain, options) {
-            Ok(instance) => instance,
-            Err(message) => panic!("{}", message),
-        }
-    }
-
     /// Parses a given string as an email address.
     ///
     /// Accessible from WASM.
@@ -147,10 +125,10 @@ impl EmailAddress {
     /// assert!(email.is_none());
     /// ```
     pub fn parse(input: &str, options: Option<ParsingOptions>) -> Option<EmailAddress> {
-        let (local_part, domain) = EmailAddress::parse_core(input, options)?;
+        let (local_part, _) = EmailAddress::parse_core(input, options)?;
         Some(EmailAddress {
-            local_part: String::from(local_part),
-            domain: String::from(domain),
+            address: String::from(input),
+            at: local_part.len(),
         })
     }
     /// Validates if the given `input` string is an email address or not.
@@ -173,7 +151,6 @@ impl EmailAddress {
     /// assert!(!EmailAddress::is_valid("test", Some(ParsingOptions::new(true))));
     /// assert!(!EmailAddress::is_valid("test", Some(ParsingOptions::new(true))));
     /// ```
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "isValid"))]
     pub fn is_valid(input: &str, options: Option<ParsingOptions>) -> bool {
         EmailAddress::parse_core(input, options).is_some()
     }
@@ -195,9 +172,8 @@ impl EmailAddress {
     /// ```
     #[doc(hidden)]
     #[allow(non_snake_case)]
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
     pub fn localPart(&self) -> String {
-        self.local_part.clone()
+        String::from(self.get_local_part())
     }
 
     /// Returns the domain of the email address.
@@ -216,18 +192,16 @@ impl EmailAddress {
     /// assert_eq!(email.domain(), "bar.com");
     /// ```
     #[doc(hidden)]
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter))]
     pub fn domain(&self) -> String {
-        self.domain.clone()
+        String::from(self.get_domain())
     }
 
     /// Returns the formatted EmailAddress.
     /// This exists purely for WASM interoperability.
     #[doc(hidden)]
     #[allow(non_snake_case)]
-    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip_typescript))]
     pub fn toString(&self) -> String {
-        format!("{}@{}", self.local_part, self.domain)
+        self.address.clone()
     }
 
     fn parse_core<'i>(
@@ -243,6 +217,109 @@ impl EmailAddress {
     #![warn(missing_docs)]
     #![warn(rustdoc::missing_doc_code_examples)]
 
+    /// A wrapper over EmailAddress::new that panics.
+    /// It is kept for backward compatibility; consider using EmailAddress::new instead.
+    /// The WASM constructor throws an `EmailAddressParseError` instead of panicking.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddress;
+    ///
+    /// let email = EmailAddress::_new("foo", "bar.com", None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the local part or domain is invalid.
+    ///
+    /// ```rust,should_panic,ignore-wasm32
+    /// use email_address_parser::EmailAddress;
+    ///
+    /// EmailAddress::_new("foo", "-bar.com", None);
+    /// ```
+    #[doc(hidden)]
+    pub fn _new(local_part: &str, domain: &str, options: Option<ParsingOptions>) -> EmailAddress {
+        match EmailAddress::new(local_part, domain, options) {
+            Ok(instance) => instance,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Parses a given string as an email address, explaining why if it is invalid.
+    ///
+    /// Returns `Ok(EmailAddress)` if the parsing is successful, else a `ParseError` with the kind of
+    /// the error and the byte offset at which it was detected.
+    /// This is as fast as `parse` for valid input; the error is diagnosed only on failure.
+    ///
+    /// Not accessible from WASM; use `EmailAddress.tryParse` there.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::try_parse("foo@bar.com", None).unwrap();
+    /// assert_eq!(email.get_domain(), "bar.com");
+    ///
+    /// let error = EmailAddress::try_parse("foo", None).unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::MissingAt);
+    /// assert_eq!(error.offset(), 3);
+    /// ```
+    pub fn try_parse(input: &str, options: Option<ParsingOptions>) -> Result<EmailAddress, ParseError> {
+        let is_lax = options.unwrap_or_default().is_lax;
+        match nom_parser::parse_address(input, is_lax) {
+            Some((local_part, _)) => Ok(EmailAddress {
+                address: String::from(input),
+                at: local_part.len(),
+            }),
+            None => Err(nom_parser::diagnose_address(input, is_lax)),
+        }
+    }
+
+    /// Parses raw octets as an email address, e.g. from mail headers, explaining why if they are invalid.
+    ///
+    /// Input that is not valid UTF-8 is rejected with `ParseErrorKind::InvalidUtf8`, at the offset
+    /// of the first invalid sequence; unless both `is_lax` and `allow_8bit` are set, in which case
+    /// every such octet is decoded as the Latin-1 character of the same value, and parsed like
+    /// any other non-ASCII character, as RFC 5322 `obs-utext` permits. The offsets of errors
+    /// always refer to `input`.
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse_bytes("foö@bar.com".as_bytes(), None, false).unwrap();
+    /// assert_eq!(email.get_local_part(), "foö");
+    ///
+    /// let error = EmailAddress::parse_bytes(b"fo\xf6@bar.com", None, false).unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidUtf8);
+    /// assert_eq!(error.offset(), 2);
+    ///
+    /// let options = ParsingOptions::new(true);
+    /// let email = EmailAddress::parse_bytes(b"fo\xf6@bar.com", Some(options.clone()), true).unwrap();
+    /// assert_eq!(email.get_local_part(), "foö");
+    ///
+    /// let error = EmailAddress::parse_bytes(b"fo\xf6@-bar.com", Some(options), true).unwrap_err();
+    /// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidDomain, 4));
+    /// ```
+    pub fn parse_bytes(input: &[u8], options: Option<ParsingOptions>, allow_8bit: bool) -> Result<EmailAddress, ParseError> {
+        let options = options.unwrap_or_default();
+        let error = match core::str::from_utf8(input) {
+            Ok(input) => return EmailAddress::try_parse(input, Some(options)),
+            Err(error) => error,
+        };
+        if !(options.is_lax && allow_8bit) {
+            return Err(ParseError::new(ParseErrorKind::InvalidUtf8, error.valid_up_to()));
+        }
+        let (decoded, substitutions) = decode_8bit(input);
+        EmailAddress::try_parse(&decoded, Some(options)).map_err(|error| {
+            // Every substituted octet takes 2 bytes once decoded.
+            let shift = substitutions.iter().take_while(|&&at| at < error.offset()).count();
+            ParseError::new(error.kind(), error.offset() - shift)
+        })
+    }
+
     /// Instantiates a new `Some(EmailAddress)` for a valid local part and domain.
     /// Returns `Err` otherwise.
     ///
@@ -259,18 +336,16 @@ impl EmailAddress {
         domain: &str,
         options: Option<ParsingOptions>,
     ) -> Result<EmailAddress, String> {
-        match EmailAddress::parse(&format!("{}@{}", local_part, domain), options.clone()) {
-            Some(email_address) => Ok(email_address),
-            None => {
-                if !options.unwrap_or_default().is_lax {
-                    return Err(format!("Invalid local part '{}'.", local_part));
-                }
-                Ok(EmailAddress {
-                    local_part: String::from(local_part),
-                    domain: String::from(domain),
-                })
-            }
+        let address = format!("{}@{}", local_part, domain);
+        if EmailAddress::parse_core(&address, options.clone()).is_none()
+            && !options.unwrap_or_default().is_lax
+        {
+            return Err(format!("Invalid local part '{}'.", local_part));
         }
+        Ok(EmailAddress {
+            address,
+            at: local_part.len(),
+        })
     }
 
     /// Returns the local part of the email address.
@@ -288,7 +363,7 @@ impl EmailAddress {
     /// assert_eq!(email.get_local_part(), "foo");
     /// ```
     pub fn get_local_part(&self) -> &str {
-        self.local_part.as_str()
+        &self.address[..self.at]
     }
     /// Returns the domain of the email address.
     ///
@@ -305,19 +380,302 @@ impl EmailAddress {
     /// assert_eq!(email.get_domain(), "bar.com");
     /// ```
     pub fn get_domain(&self) -> &str {
-        self.domain.as_str()
+        &self.address[self.at + 1..]
+    }
+
+    /// Returns the whole email address as a string slice.
+    ///
+    /// This is the same string as produced by the `Display` implementation, without any allocation.
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddress;
+    ///
+    /// let email = EmailAddress::new("foo", "bar.com", None).unwrap();
+    /// assert_eq!(email.as_str(), "foo@bar.com");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.address.as_str()
+    }
+
+    /// Consumes the email address and returns the underlying string buffer.
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddress;
+    ///
+    /// let email = EmailAddress::parse("foo@bar.com", None).unwrap();
+    /// assert_eq!(email.into_string(), String::from("foo@bar.com"));
+    /// ```
+    pub fn into_string(self) -> String {
+        self.address
+    }
+
+    /// Returns a borrowed `EmailAddressRef` view of this email address.
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddress;
+    ///
+    /// let email = EmailAddress::parse("foo@bar.com", None).unwrap();
+    /// let email_ref = email.as_email_ref();
+    /// assert_eq!(email_ref.get_local_part(), "foo");
+    /// assert_eq!(email_ref.get_domain(), "bar.com");
+    /// ```
+    pub fn as_email_ref(&self) -> EmailAddressRef<'_> {
+        EmailAddressRef {
+            local_part: self.get_local_part(),
+            domain: self.get_domain(),
+        }
     }
 }
 
 impl fmt::Display for EmailAddress {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        formatter.write_str(&self.address)
+    }
+}
+
+/// Allows conversion from an owned `String` to EmailAddress, reusing its allocation.
+/// This uses the default `ParsingOptions`.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::EmailAddress;
+/// use std::convert::TryFrom;
+///
+/// let email = EmailAddress::try_from(String::from("foo@bar.com")).unwrap();
+/// assert_eq!(email.get_domain(), "bar.com");
+/// assert!(EmailAddress::try_from(String::from("foo@-bar.com")).is_err());
+/// ```
+impl TryFrom<String> for EmailAddress {
+    type Error = fmt::Error;
+
+    fn try_from(address: String) -> Result<Self, Self::Error> {
+        let at = match EmailAddress::parse_core(&address, None) {
+            Some((local_part, _)) => local_part.len(),
+            None => return Err(fmt::Error),
+        };
+        Ok(EmailAddress { address, at })
+    }
+}
+
+impl From<EmailAddress> for String {
+    fn from(email: EmailAddress) -> Self {
+        email.into_string()
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Allows looking up an `EmailAddress` by `&str` in collections.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::EmailAddress;
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(EmailAddress::parse("foo@bar.com", None).unwrap());
+/// assert!(set.contains("foo@bar.com"));
+/// ```
+impl Borrow<str> for EmailAddress {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for EmailAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address
+    }
+}
+
+impl Eq for EmailAddress {}
+
+impl PartialEq<str> for EmailAddress {
+    fn eq(&self, other: &str) -> bool {
+        self.address == other
+    }
+}
+
+impl PartialEq<&str> for EmailAddress {
+    fn eq(&self, other: &&str) -> bool {
+        self.address == *other
+    }
+}
+
+impl Hash for EmailAddress {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address.hash(state)
+    }
+}
+
+impl PartialOrd for EmailAddress {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EmailAddress {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.address.cmp(&other.address)
+    }
+}
+
+/// Borrowed, zero-copy counterpart of [`EmailAddress`].
+///
+/// The local part and domain are slices into the parsed input, so parsing
+/// and accessing the components does not allocate.
+/// Use [`EmailAddressRef::to_owned`] to obtain an owned [`EmailAddress`].
+///
+/// Not accessible from WASM.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::EmailAddressRef;
+///
+/// let input = String::from("foo@bar.com");
+/// let email = EmailAddressRef::parse(&input, None).unwrap();
+/// assert_eq!(email.get_local_part(), "foo");
+/// assert_eq!(email.get_domain(), "bar.com");
+/// assert_eq!(format!("{}", email), "foo@bar.com");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EmailAddressRef<'a> {
+    local_part: &'a str,
+    domain: &'a str,
+}
+
+impl<'a> EmailAddressRef<'a> {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Parses a given string as an email address without copying it.
+    ///
+    /// Returns `Some(EmailAddressRef)` if the parsing is successful, else `None`.
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// // strict parsing
+    /// let email = EmailAddressRef::parse("foo@bar.com", None);
+    /// assert!(email.is_some());
+    ///
+    /// // non-strict parsing
+    /// let email = EmailAddressRef::parse("\u{0d}\u{0a} \u{0d}\u{0a} test@iana.org", Some(ParsingOptions::new(true)));
+    /// assert!(email.is_some());
+    ///
+    /// // parsing invalid address
+    /// assert!(EmailAddressRef::parse("test@-iana.org", None).is_none());
+    /// ```
+    pub fn parse(input: &'a str, options: Option<ParsingOptions>) -> Option<EmailAddressRef<'a>> {
+        let (local_part, domain) = EmailAddress::parse_core(input, options)?;
+        Some(EmailAddressRef { local_part, domain })
+    }
+
+    /// Returns the local part of the email address.
+    ///
+    /// The returned slice borrows from the parsed input, not from `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddressRef;
+    ///
+    /// let email = EmailAddressRef::parse("foo@bar.com", None).unwrap();
+    /// assert_eq!(email.get_local_part(), "foo");
+    /// ```
+    pub fn get_local_part(&self) -> &'a str {
+        self.local_part
+    }
+
+    /// Returns the domain of the email address.
+    ///
+    /// The returned slice borrows from the parsed input, not from `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::EmailAddressRef;
+    ///
+    /// let email = EmailAddressRef::parse("foo@bar.com", None).unwrap();
+    /// assert_eq!(email.get_domain(), "bar.com");
+    /// ```
+    pub fn get_domain(&self) -> &'a str {
+        self.domain
+    }
+
+    /// Copies the borrowed components into an owned `EmailAddress`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddressRef::parse("foo@bar.com", None).unwrap().to_owned();
+    /// assert_eq!(email, EmailAddress::parse("foo@bar.com", None).unwrap());
+    /// ```
+    pub fn to_owned(self) -> EmailAddress {
+        let mut address = String::with_capacity(self.local_part.len() + 1 + self.domain.len());
+        address.push_str(self.local_part);
+        address.push('@');
+        address.push_str(self.domain);
+        EmailAddress {
+            address,
+            at: self.local_part.len(),
+        }
+    }
+}
+
+impl<'a> From<EmailAddressRef<'a>> for EmailAddress {
+    fn from(email: EmailAddressRef<'a>) -> Self {
+        email.to_owned()
+    }
+}
+
+impl fmt::Display for EmailAddressRef<'_> {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         formatter.write_fmt(format_args!("{}@{}", self.local_part, self.domain))
     }
 }
 
+/// Decodes `input` as UTF-8, substituting the Latin-1 character of the same value for every octet
+/// that is not part of a valid sequence. Also returns the offsets of the substitutions in the output.
+fn decode_8bit(mut input: &[u8]) -> (String, Vec<usize>) {
+    let mut decoded = String::with_capacity(input.len() + input.len() / 2);
+    let mut substitutions = Vec::new();
+    loop {
+        match core::str::from_utf8(input) {
+            Ok(valid) => {
+                decoded.push_str(valid);
+                return (decoded, substitutions);
+            }
+            Err(error) => {
+                let (valid, rest) = input.split_at(error.valid_up_to());
+                decoded.push_str(core::str::from_utf8(valid).unwrap_or_default());
+                let invalid_len = error.error_len().unwrap_or(rest.len());
+                for &octet in &rest[..invalid_len] {
+                    substitutions.push(decoded.len());
+                    decoded.push(char::from(octet));
+                }
+                input = &rest[invalid_len..];
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse_error::ParseErrorKind;
 
     #[test]
     fn email_address_instantiation_works() {
@@ -339,6 +697,114 @@ mod tests {
         assert_ne!(foo_at_bar_dot_com_2, foob_at_ar_dot_com);
     }
 
+    #[test]
+    fn email_address_ref_borrows_from_input() {
+        let input = String::from("foo@bar.com");
+        let email = EmailAddressRef::parse(&input, None).unwrap();
+        assert!(std::ptr::eq(email.get_local_part().as_ptr(), input.as_ptr()));
+        assert!(std::ptr::eq(email.get_domain().as_ptr(), input[4..].as_ptr()));
+        assert_eq!(format!("{}", email), input);
+    }
+
+    #[test]
+    fn email_address_ref_round_trips_to_owned() {
+        let email = EmailAddress::parse(" test @iana.org", None).unwrap();
+        let email_ref = email.as_email_ref();
+        assert_eq!(email_ref.to_owned(), email);
+        assert_eq!(EmailAddress::from(email_ref), email);
+        assert_eq!(EmailAddressRef::parse(" test @iana.org", None), Some(email_ref));
+    }
+
+    #[test]
+    fn email_address_uses_single_buffer() {
+        let input = String::from("foo@bar.com");
+        let ptr = input.as_ptr();
+        let email = EmailAddress::try_from(input).unwrap();
+        assert_eq!(email.as_str().as_ptr(), ptr);
+        assert_eq!(email.get_local_part(), "foo");
+        assert_eq!(email.get_domain(), "bar.com");
+        let address = email.into_string();
+        assert_eq!(address.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn email_address_keeps_lax_split_on_at() {
+        let email = EmailAddress::new("a@b", "c", Some(ParsingOptions::new(true))).unwrap();
+        assert_eq!(email.get_local_part(), "a@b");
+        assert_eq!(email.get_domain(), "c");
+        assert_eq!(email.as_str(), "a@b@c");
+    }
+
+    #[test]
+    fn email_address_can_be_looked_up_by_str() {
+        let mut set = std::collections::BTreeSet::new();
+        set.insert(EmailAddress::parse("foo@bar.com", None).unwrap());
+        assert!(set.contains("foo@bar.com"));
+        assert!(!set.contains("foo@baz.com"));
+    }
+
+    #[test]
+    fn try_parse_reports_kind_and_offset() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0),
+            ("foo", ParseErrorKind::MissingAt, 3),
+            ("@bar.com", ParseErrorKind::InvalidLocalPart, 0),
+            ("foo bar@bar.com", ParseErrorKind::InvalidLocalPart, 4),
+            ("foo.@bar.com", ParseErrorKind::InvalidLocalPart, 4),
+            ("foo@", ParseErrorKind::InvalidDomain, 4),
+            ("foo@-bar.com", ParseErrorKind::InvalidDomain, 4),
+            ("foo@bar..com", ParseErrorKind::InvalidDomain, 8),
+            ("foo@bar.com]", ParseErrorKind::InvalidDomain, 11),
+            ("foö@bär.com-", ParseErrorKind::InvalidDomain, 13),
+        ];
+        for (input, kind, offset) in cases {
+            let error = EmailAddress::try_parse(input, None).unwrap_err();
+            assert_eq!((error.kind(), error.offset()), (kind, offset), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn try_parse_diagnoses_with_lax_rules() {
+        assert!(EmailAddress::try_parse("test . test@iana.org", Some(ParsingOptions::new(true))).is_ok());
+        let error = EmailAddress::try_parse("test . test@iana.org", None).unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidLocalPart);
+        let error = EmailAddress::try_parse("test . test@-iana.org", Some(ParsingOptions::new(true))).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidDomain, 12));
+    }
+
+    #[test]
+    fn parse_bytes_reports_invalid_utf8() {
+        let cases: [(&[u8], usize); 4] = [
+            (b"\xff", 0),
+            (b"foo@bar.co\xe9", 10),
+            (b"\"f\xc3o\"@bar.com", 2),
+            (b"f\xc3\xb6\xf0\x9f\x98@bar.com", 3),
+        ];
+        for (input, offset) in cases {
+            let error = EmailAddress::parse_bytes(input, Some(ParsingOptions::new(true)), false).unwrap_err();
+            assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidUtf8, offset), "{:?}", input);
+        }
+        let error = EmailAddress::parse_bytes(b"foo@-bar.com", None, false).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidDomain, 4));
+    }
+
+    #[test]
+    fn parse_bytes_accepts_8bit_octets_in_lax_mode() {
+        let options = ParsingOptions::new(true);
+        let email = EmailAddress::parse_bytes(b"\"J\xfcrgen\"@m\xfcnchen.de", Some(options.clone()), true).unwrap();
+        assert_eq!(email.to_string(), "\"Jürgen\"@münchen.de");
+        let email = EmailAddress::parse_bytes(b"f\xc3\xb6\xf0\x9f\x98@bar.com", Some(options.clone()), true).unwrap();
+        assert_eq!(email.get_local_part(), "fö\u{f0}\u{9f}\u{98}");
+
+        let error = EmailAddress::parse_bytes(b"\xe9\xe9 \xe9@bar.com", Some(options.clone()), true).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidLocalPart, 3));
+        let error = EmailAddress::parse_bytes(b"\xe9@b\xe9r.com-", Some(options.clone()), true).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (ParseErrorKind::InvalidDomain, 10));
+
+        let error = EmailAddress::parse_bytes(b"\xe9@bar.com", None, true).unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidUtf8);
+    }
+
     #[test]
     fn domain_rule_does_not_parse_dash_google_dot_com() {
         assert_eq!(nom_parser::test_parse_domain_complete("-google.com"), false);
@@ -435,7 +901,7 @@ mod tests {
         let actual = EmailAddress::new("", "", Some(ParsingOptions::new(true)));
         assert_eq!(actual.is_ok(), true, "expected ok");
         let actual = actual.unwrap();
-        assert_eq!(actual.domain, "");
-        assert_eq!(actual.local_part, "");
+        assert_eq!(actual.get_domain(), "");
+        assert_eq!(actual.get_local_part(), "");
     }
 }
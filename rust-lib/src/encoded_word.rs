@@ -0,0 +1,492 @@
+//! Display names of mailboxes (`phrase` in RFC 5322), with the encoded-words of
+//! [RFC 2047](https://tools.ietf.org/html/rfc2047) that carry non-ASCII text in 7-bit headers.
+use crate::cfws::{skip_comment, unquote};
+use crate::email_address::EmailAddress;
+use crate::nom_parser::{is_atext, is_qtext_char};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The longest encoded-word allowed by RFC 2047.
+const MAX_ENCODED_WORD_LEN: usize = 75;
+const ENCODED_WORD_OVERHEAD: usize = "=?UTF-8?Q??=".len();
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+/// Windows-1252 characters for the octets 0x80 to 0x9F; the undefined ones map to the C1 controls,
+/// as in the WHATWG encoding standard.
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž', '\u{8f}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}', 'ž', 'Ÿ',
+];
+
+/// The display name of a mailbox; e.g. `Jörg` in `=?UTF-8?B?SsO2cmc=?= <j@x.de>`.
+///
+/// Holds the name as Unicode text. [`DisplayName::decode`] reads it from a header, decoding
+/// RFC 2047 encoded-words, and [`DisplayName::encode`] writes it back for a 7-bit transport.
+/// Encoded-words in the `B` and `Q` encodings are decoded from the UTF-8, US-ASCII, ISO-8859-1,
+/// and Windows-1252 charsets; others are kept as they are, as RFC 2047 recommends.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let name = DisplayName::decode("=?UTF-8?B?SsO2cmc=?=");
+/// assert_eq!(name.as_str(), "Jörg");
+///
+/// let email = EmailAddress::parse("j@x.de", None).unwrap();
+/// assert_eq!(name.to_mailbox(&email), "=?UTF-8?B?SsO2cmc=?= <j@x.de>");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DisplayName {
+    name: String,
+}
+
+impl DisplayName {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Instantiates a display name from Unicode text.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisplayName;
+    ///
+    /// assert_eq!(DisplayName::new("Jörg").as_str(), "Jörg");
+    /// ```
+    pub fn new(name: &str) -> DisplayName {
+        DisplayName {
+            name: String::from(name),
+        }
+    }
+
+    /// Decodes the display name from the `phrase` of a header, as it appears before `<`.
+    ///
+    /// Encoded-words are decoded, and the white space between adjacent encoded-words is dropped.
+    /// Quoted strings are unquoted, comments are dropped, and folded lines are unfolded.
+    /// Malformed encoded-words, and those in unsupported charsets, are kept as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisplayName;
+    ///
+    /// let name = DisplayName::decode("=?ISO-8859-1?Q?Andr=E9?= Pirard");
+    /// assert_eq!(name.as_str(), "André Pirard");
+    ///
+    /// let name = DisplayName::decode("\"Smith, John\" (Sales)");
+    /// assert_eq!(name.as_str(), "Smith, John");
+    /// ```
+    pub fn decode(phrase: &str) -> DisplayName {
+        let mut decoder = Decoder::default();
+        let mut rest = phrase;
+        while let Some(ch) = rest.chars().next() {
+            rest = match ch {
+                ' ' | '\t' | '\r' | '\n' => {
+                    let end = rest.find(|ch| !matches!(ch, ' ' | '\t' | '\r' | '\n')).unwrap_or(rest.len());
+                    decoder.white_space(&rest[..end]);
+                    &rest[end..]
+                }
+                '"' => {
+                    let (text, rest) = unquote(&rest[1..]);
+                    decoder.text(&text);
+                    rest
+                }
+                '(' => {
+                    decoder.white_space(" ");
+                    skip_comment(&rest[1..])
+                }
+                _ => {
+                    let end = rest.find([' ', '\t', '\r', '\n', '"', '(']).unwrap_or(rest.len());
+                    decoder.word(&rest[..end]);
+                    &rest[end..]
+                }
+            };
+        }
+        DisplayName {
+            name: decoder.finish(),
+        }
+    }
+
+    /// Returns the display name as Unicode text.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisplayName;
+    ///
+    /// assert_eq!(DisplayName::decode("=?UTF-8?Q?J=C3=B6rg?=").as_str(), "Jörg");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Encodes the display name as a `phrase` for a 7-bit header.
+    ///
+    /// Names made of atoms (`atext`) separated by single spaces are written as they are, and other
+    /// ASCII names as a quoted string. Names with non-ASCII or control characters are written as
+    /// UTF-8 encoded-words, in the `Q` or `B` encoding, whichever is shorter, and at most 75
+    /// characters long each.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::DisplayName;
+    ///
+    /// assert_eq!(DisplayName::new("John Smith").encode(), "John Smith");
+    /// assert_eq!(DisplayName::new("Smith, John").encode(), "\"Smith, John\"");
+    /// assert_eq!(DisplayName::new("Jörg").encode(), "=?UTF-8?B?SsO2cmc=?=");
+    /// assert_eq!(DisplayName::new("日本").encode(), "=?UTF-8?B?5pel5pys?=");
+    /// ```
+    pub fn encode(&self) -> String {
+        let name = self.name.as_str();
+        if !name.chars().all(|ch| ch == ' ' || ch.is_ascii_graphic()) {
+            return encode_words(name);
+        }
+        let is_atoms = name
+            .split(' ')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext) && !is_encoded_word(atom));
+        if is_atoms {
+            return String::from(name);
+        }
+        let mut quoted = String::with_capacity(name.len() + 2);
+        quoted.push('"');
+        for ch in name.chars() {
+            if !is_qtext_char(ch) && ch != ' ' {
+                quoted.push('\\');
+            }
+            quoted.push(ch);
+        }
+        quoted.push('"');
+        quoted
+    }
+
+    /// Writes a mailbox (`name-addr`) made of the encoded display name and `address`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("john@example.com", None).unwrap();
+    /// assert_eq!(DisplayName::new("Smith, John").to_mailbox(&email), "\"Smith, John\" <john@example.com>");
+    /// ```
+    pub fn to_mailbox(&self, address: &EmailAddress) -> String {
+        format!("{} <{}>", self.encode(), address)
+    }
+}
+
+impl fmt::Display for DisplayName {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.name)
+    }
+}
+
+/// Accumulates the decoded phrase, holding back white space and the octets of encoded-words until
+/// it is known whether they are followed by another encoded-word in the same charset; multi-byte
+/// characters are sometimes split between encoded-words.
+#[derive(Default)]
+struct Decoder<'a> {
+    decoded: String,
+    white_space: String,
+    pending: Option<Pending<'a>>,
+}
+
+struct Pending<'a> {
+    charset: &'a str,
+    octets: Vec<u8>,
+    raw: String,
+}
+
+impl<'a> Decoder<'a> {
+    fn white_space(&mut self, white_space: &str) {
+        // Unfolding removes the line breaks of folded lines.
+        self.white_space.extend(white_space.chars().filter(|ch| !matches!(ch, '\r' | '\n')));
+    }
+
+    fn word(&mut self, word: &'a str) {
+        let Some((charset, octets)) = decode_encoded_word(word) else {
+            return self.text(word);
+        };
+        match &mut self.pending {
+            Some(pending) if pending.charset.eq_ignore_ascii_case(charset) => {
+                pending.octets.extend(octets);
+                pending.raw.push_str(&self.white_space);
+                pending.raw.push_str(word);
+            }
+            _ => {
+                // White space between adjacent encoded-words is dropped whatever their charsets.
+                let mut raw = String::new();
+                if self.pending.is_some() {
+                    self.flush();
+                    raw.push_str(&self.white_space);
+                } else {
+                    self.push_white_space();
+                }
+                raw.push_str(word);
+                self.pending = Some(Pending { charset, octets, raw });
+            }
+        }
+        self.white_space.clear();
+    }
+
+    fn text(&mut self, text: &str) {
+        self.flush();
+        self.push_white_space();
+        self.decoded.push_str(text);
+    }
+
+    fn push_white_space(&mut self) {
+        if !self.decoded.is_empty() {
+            self.decoded.push_str(&self.white_space);
+        }
+        self.white_space.clear();
+    }
+
+    // White space between encoded-words is dropped, so `raw` only keeps it for undecodable ones.
+    fn flush(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            match decode_charset(pending.charset, &pending.octets) {
+                Some(text) => self.decoded.push_str(&text),
+                None => self.decoded.push_str(&pending.raw),
+            }
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        self.decoded
+    }
+}
+
+/// Returns the charset and the decoded octets of an encoded-word: `=?charset?encoding?encoded-text?=`.
+fn decode_encoded_word(word: &str) -> Option<(&str, Vec<u8>)> {
+    let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = inner.splitn(3, '?');
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let text = parts.next()?;
+    if charset.is_empty() || text.contains('?') || !text.bytes().all(|octet| octet.is_ascii_graphic()) {
+        return None;
+    }
+    // RFC 2231 allows a language after the charset; e.g. `UTF-8*en`.
+    let charset = charset.split('*').next()?;
+    let octets = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_q(text)?,
+        _ => return None,
+    };
+    Some((charset, octets))
+}
+
+fn is_encoded_word(word: &str) -> bool {
+    decode_encoded_word(word).is_some()
+}
+
+fn decode_charset(charset: &str, octets: &[u8]) -> Option<String> {
+    let is = |name: &str| charset.eq_ignore_ascii_case(name);
+    if is("UTF-8") || is("UTF8") {
+        String::from_utf8(octets.to_vec()).ok()
+    } else if is("US-ASCII") || is("ASCII") {
+        octets.is_ascii().then(|| octets.iter().map(|&octet| char::from(octet)).collect())
+    } else if is("ISO-8859-1") || is("ISO_8859-1") || is("LATIN1") {
+        Some(octets.iter().map(|&octet| char::from(octet)).collect())
+    } else if is("WINDOWS-1252") || is("CP1252") {
+        let decode = |octet: u8| match octet {
+            0x80..=0x9f => WINDOWS_1252[usize::from(octet - 0x80)],
+            _ => char::from(octet),
+        };
+        Some(octets.iter().map(|&octet| decode(octet)).collect())
+    } else {
+        None
+    }
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut octets = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for octet in text.bytes() {
+        let value = BASE64.iter().position(|&digit| digit == octet)? as u32;
+        buffer = (buffer << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            octets.push((buffer >> bits) as u8);
+        }
+    }
+    Some(octets)
+}
+
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let mut octets = Vec::with_capacity(text.len());
+    let mut bytes = text.bytes();
+    while let Some(octet) = bytes.next() {
+        octets.push(match octet {
+            b'_' => b' ',
+            b'=' => {
+                let high = char::from(bytes.next()?).to_digit(16)?;
+                let low = char::from(bytes.next()?).to_digit(16)?;
+                (high * 16 + low) as u8
+            }
+            _ => octet,
+        });
+    }
+    Some(octets)
+}
+
+// RFC 2047 restricts `Q` encoded-words in a phrase to these characters, besides `=` and `_`.
+fn is_q_literal(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || matches!(ch, '!' | '*' | '+' | '-' | '/')
+}
+
+fn q_len(ch: char) -> usize {
+    if is_q_literal(ch) || ch == ' ' {
+        1
+    } else {
+        3 * ch.len_utf8()
+    }
+}
+
+fn base64_len(octets: usize) -> usize {
+    octets.div_ceil(3) * 4
+}
+
+/// Writes `name` as UTF-8 encoded-words separated by spaces.
+fn encode_words(name: &str) -> String {
+    let is_q = name.chars().map(q_len).sum::<usize>() <= base64_len(name.len());
+    let capacity = MAX_ENCODED_WORD_LEN - ENCODED_WORD_OVERHEAD;
+    let mut words: Vec<String> = Vec::new();
+    let mut chunk_start = 0;
+    let mut chunk_len = 0;
+    for (index, ch) in name.char_indices() {
+        let len = if is_q {
+            chunk_len + q_len(ch)
+        } else {
+            base64_len(index + ch.len_utf8() - chunk_start)
+        };
+        if len > capacity {
+            words.push(encode_word(&name[chunk_start..index], is_q));
+            chunk_start = index;
+            chunk_len = if is_q { q_len(ch) } else { 0 };
+        } else {
+            chunk_len = len;
+        }
+    }
+    words.push(encode_word(&name[chunk_start..], is_q));
+    words.join(" ")
+}
+
+fn encode_word(text: &str, is_q: bool) -> String {
+    let mut word = String::from(if is_q { "=?UTF-8?Q?" } else { "=?UTF-8?B?" });
+    if is_q {
+        for ch in text.chars() {
+            if is_q_literal(ch) {
+                word.push(ch);
+            } else if ch == ' ' {
+                word.push('_');
+            } else {
+                let mut buffer = [0; 4];
+                for &octet in ch.encode_utf8(&mut buffer).as_bytes() {
+                    word.push('=');
+                    word.push(char::from(HEX[usize::from(octet >> 4)]));
+                    word.push(char::from(HEX[usize::from(octet & 0xf)]));
+                }
+            }
+        }
+    } else {
+        for group in text.as_bytes().chunks(3) {
+            let buffer = group.iter().enumerate().fold(0u32, |buffer, (i, &octet)| buffer | u32::from(octet) << (16 - 8 * i));
+            for i in 0..4 {
+                word.push(if i <= group.len() {
+                    char::from(BASE64[(buffer >> (18 - 6 * i) & 0x3f) as usize])
+                } else {
+                    '='
+                });
+            }
+        }
+    }
+    word.push_str("?=");
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_rfc_2047_examples() {
+        let cases = [
+            ("=?ISO-8859-1?Q?a?=", "a"),
+            ("=?ISO-8859-1?Q?a?= b", "a b"),
+            ("=?ISO-8859-1?Q?a?= =?ISO-8859-1?Q?b?=", "ab"),
+            ("=?ISO-8859-1?Q?a?=  =?ISO-8859-1?Q?b?=", "ab"),
+            ("=?ISO-8859-1?Q?a?=\r\n    =?ISO-8859-1?Q?b?=", "ab"),
+            ("=?ISO-8859-1?Q?a_b?=", "a b"),
+            ("=?US-ASCII?Q?Keith_Moore?=", "Keith Moore"),
+            ("=?ISO-8859-1?Q?Keld_J=F8rn_Simonsen?=", "Keld Jørn Simonsen"),
+            ("=?ISO-8859-1?Q?Olle_J=E4rnefors?=", "Olle Järnefors"),
+            ("Nathaniel Borenstein (=?iso-8859-8?b?7eXs+SDv4SDp7Oj08A==?=)", "Nathaniel Borenstein"),
+        ];
+        for (phrase, name) in cases {
+            assert_eq!(DisplayName::decode(phrase).as_str(), name, "{:?}", phrase);
+        }
+    }
+
+    #[test]
+    fn decodes_charsets_and_joins_split_characters() {
+        assert_eq!(DisplayName::decode("=?utf-8?b?SsO2cmc?=").as_str(), "Jörg");
+        assert_eq!(DisplayName::decode("=?UTF-8*de?Q?J=C3=B6rg?=").as_str(), "Jörg");
+        assert_eq!(DisplayName::decode("=?windows-1252?Q?=80_5?=").as_str(), "€ 5");
+        assert_eq!(DisplayName::decode("=?UTF-8?Q?J=C3?= =?UTF-8?Q?=B6rg?=").as_str(), "Jörg");
+        assert_eq!(DisplayName::decode("Jörg =?UTF-8?Q?M=C3=BCller?=").as_str(), "Jörg Müller");
+        assert_eq!(DisplayName::decode("=?UTF-8?Q?a?= =?ISO-8859-1?Q?b?=").as_str(), "ab");
+        assert_eq!(DisplayName::decode("=?UTF-8?Q?J=C3=B6rg?=\r\n =?ISO-8859-1?Q?M=FCller?=").as_str(), "JörgMüller");
+    }
+
+    #[test]
+    fn keeps_undecodable_words() {
+        let cases = [
+            "=?KOI8-R?Q?a?=",
+            "=?UTF-8?Q?=C3?=",
+            "=?UTF-8?Q?=C3?= =?UTF-8?Q?=C3?=",
+            "=?UTF-8?X?a?=",
+            "=?UTF-8?B?!!?=",
+            "=?UTF-8?Q?a b?=",
+            "=??Q?a?=",
+        ];
+        for phrase in cases {
+            assert_eq!(DisplayName::decode(phrase).as_str(), phrase, "{:?}", phrase);
+        }
+        assert_eq!(DisplayName::decode("\"=?UTF-8?Q?a?=\"").as_str(), "=?UTF-8?Q?a?=");
+        assert_eq!(DisplayName::decode("=?UTF-8?Q?a?= =?KOI8-R?Q?b?=").as_str(), "a =?KOI8-R?Q?b?=");
+        assert_eq!(DisplayName::decode(" \"a\\\"b\"  c ").as_str(), "a\"b  c");
+    }
+
+    #[test]
+    fn encodes_for_7bit_transport() {
+        assert_eq!(DisplayName::new("a  b").encode(), "\"a  b\"");
+        assert_eq!(DisplayName::new("a\"b\\").encode(), "\"a\\\"b\\\\\"");
+        assert_eq!(DisplayName::new("=?UTF-8?Q?a?=").encode(), "\"=?UTF-8?Q?a?=\"");
+        assert_eq!(DisplayName::new("").encode(), "\"\"");
+        assert_eq!(DisplayName::new("a\tb").encode(), "=?UTF-8?B?YQli?=");
+        assert_eq!(DisplayName::new("Jörg Müller").encode(), "=?UTF-8?B?SsO2cmcgTcO8bGxlcg==?=");
+        assert_eq!(DisplayName::new("Keld Jørn Simonsen").encode(), "=?UTF-8?Q?Keld_J=C3=B8rn_Simonsen?=");
+    }
+
+    #[test]
+    fn round_trips() {
+        let names = [
+            "Jörg",
+            "Smith, John",
+            "=?UTF-8?Q?a?=",
+            "日本語のテキスト、かなり長いので、複数のエンコードされた単語に分割されます",
+            "Straße mit einem ziemlich langen Namen, der umgebrochen werden muss 😀",
+            "a\"b\\c",
+            " leading and trailing ",
+        ];
+        for name in names {
+            let encoded = DisplayName::new(name).encode();
+            assert!(encoded.is_ascii(), "{:?}", encoded);
+            assert!(encoded.split(' ').all(|word| word.len() <= MAX_ENCODED_WORD_LEN), "{:?}", encoded);
+            assert_eq!(DisplayName::decode(&encoded).as_str(), name, "{:?}", encoded);
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! C API over `EmailAddress`, exported from the `cdylib`.
+//!
+//! The generated header is `include/email_address_parser.h`; regenerate it with
+//! `cbindgen --config cbindgen.toml --output include/email_address_parser.h` after changing this module.
+//! All strings passed in are NUL-terminated UTF-8. Strings handed out are either borrowed
+//! (`EapStr`, valid as long as the owning `EapEmailAddress`) or owned, and must then be
+//! released with `eap_string_free`.
+use crate::email_address::{EmailAddress, ParsingOptions};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+/// Status codes returned by the fallible functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EapStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// The input is not a valid email address.
+    InvalidAddress = 1,
+    /// A required pointer argument is `NULL`.
+    NullPointer = 2,
+    /// An input string is not valid UTF-8.
+    InvalidUtf8 = 3,
+}
+
+/// Options for parsing; mirrors `ParsingOptions`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EapParsingOptions {
+    /// Enables lax parsing; i.e. obsolete parts as defined by RFC 5322 are allowed.
+    pub is_lax: bool,
+}
+
+/// A borrowed, non-NUL-terminated UTF-8 string.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct EapStr {
+    /// Pointer to the first byte.
+    pub ptr: *const c_char,
+    /// Length in bytes.
+    pub len: usize,
+}
+
+/// Opaque handle to a parsed email address; release it with `eap_email_address_free`.
+pub struct EapEmailAddress {
+    inner: EmailAddress,
+}
+
+impl From<&EapParsingOptions> for ParsingOptions {
+    fn from(options: &EapParsingOptions) -> Self {
+        ParsingOptions::new(options.is_lax)
+    }
+}
+
+impl From<&str> for EapStr {
+    fn from(value: &str) -> Self {
+        EapStr {
+            ptr: value.as_ptr() as *const c_char,
+            len: value.len(),
+        }
+    }
+}
+
+unsafe fn to_str<'a>(input: *const c_char) -> Result<&'a str, EapStatus> {
+    if input.is_null() {
+        return Err(EapStatus::NullPointer);
+    }
+    CStr::from_ptr(input).to_str().map_err(|_| EapStatus::InvalidUtf8)
+}
+
+unsafe fn to_options(options: *const EapParsingOptions) -> Option<ParsingOptions> {
+    options.as_ref().map(ParsingOptions::from)
+}
+
+unsafe fn store(result: Result<EmailAddress, EapStatus>, out: *mut *mut EapEmailAddress) -> EapStatus {
+    match result {
+        Ok(inner) => {
+            *out = Box::into_raw(Box::new(EapEmailAddress { inner }));
+            EapStatus::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+/// Returns the default parsing options; i.e. strict parsing.
+#[no_mangle]
+pub extern "C" fn eap_parsing_options_default() -> EapParsingOptions {
+    EapParsingOptions {
+        is_lax: ParsingOptions::default().is_lax,
+    }
+}
+
+/// Parses `input` as an email address.
+///
+/// On success, stores a new handle in `*out` and returns `EAP_STATUS_OK`; `*out` is left untouched otherwise.
+/// `options` may be `NULL` for the default options.
+///
+/// # Safety
+/// `input` must be `NULL` or a NUL-terminated string, `options` must be `NULL` or valid,
+/// and `out` must be `NULL` or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn eap_parse(
+    input: *const c_char,
+    options: *const EapParsingOptions,
+    out: *mut *mut EapEmailAddress,
+) -> EapStatus {
+    if out.is_null() {
+        return EapStatus::NullPointer;
+    }
+    let result = to_str(input).and_then(|input| {
+        EmailAddress::parse(input, to_options(options)).ok_or(EapStatus::InvalidAddress)
+    });
+    store(result, out)
+}
+
+/// Instantiates an email address from a local part and a domain; mirrors `EmailAddress::new`.
+///
+/// On success, stores a new handle in `*out` and returns `EAP_STATUS_OK`; `*out` is left untouched otherwise.
+/// `options` may be `NULL` for the default options.
+///
+/// # Safety
+/// `local_part` and `domain` must be `NULL` or NUL-terminated strings, `options` must be `NULL` or valid,
+/// and `out` must be `NULL` or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn eap_new(
+    local_part: *const c_char,
+    domain: *const c_char,
+    options: *const EapParsingOptions,
+    out: *mut *mut EapEmailAddress,
+) -> EapStatus {
+    if out.is_null() {
+        return EapStatus::NullPointer;
+    }
+    let result = to_str(local_part).and_then(|local_part| {
+        let domain = to_str(domain)?;
+        EmailAddress::new(local_part, domain, to_options(options)).map_err(|_| EapStatus::InvalidAddress)
+    });
+    store(result, out)
+}
+
+/// Returns `true` if `input` is a valid email address, `false` otherwise (including for `NULL` or non-UTF-8 input).
+///
+/// `options` may be `NULL` for the default options.
+///
+/// # Safety
+/// `input` must be `NULL` or a NUL-terminated string, and `options` must be `NULL` or valid.
+#[no_mangle]
+pub unsafe extern "C" fn eap_is_valid(input: *const c_char, options: *const EapParsingOptions) -> bool {
+    match to_str(input) {
+        Ok(input) => EmailAddress::is_valid(input, to_options(options)),
+        Err(_) => false,
+    }
+}
+
+/// Returns the local part of `address`, borrowed from it; `{NULL, 0}` if `address` is `NULL`.
+///
+/// # Safety
+/// `address` must be `NULL` or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn eap_email_address_local_part(address: *const EapEmailAddress) -> EapStr {
+    match address.as_ref() {
+        Some(address) => EapStr::from(address.inner.get_local_part()),
+        None => EapStr { ptr: ptr::null(), len: 0 },
+    }
+}
+
+/// Returns the domain of `address`, borrowed from it; `{NULL, 0}` if `address` is `NULL`.
+///
+/// # Safety
+/// `address` must be `NULL` or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn eap_email_address_domain(address: *const EapEmailAddress) -> EapStr {
+    match address.as_ref() {
+        Some(address) => EapStr::from(address.inner.get_domain()),
+        None => EapStr { ptr: ptr::null(), len: 0 },
+    }
+}
+
+/// Returns the whole address, borrowed from it; `{NULL, 0}` if `address` is `NULL`.
+///
+/// # Safety
+/// `address` must be `NULL` or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn eap_email_address_as_str(address: *const EapEmailAddress) -> EapStr {
+    match address.as_ref() {
+        Some(address) => EapStr::from(address.inner.as_str()),
+        None => EapStr { ptr: ptr::null(), len: 0 },
+    }
+}
+
+/// Returns the whole address as a new NUL-terminated string, to be released with `eap_string_free`.
+///
+/// Returns `NULL` if `address` is `NULL`, or if the address contains a NUL character (possible with lax parsing).
+///
+/// # Safety
+/// `address` must be `NULL` or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn eap_email_address_to_string(address: *const EapEmailAddress) -> *mut c_char {
+    match address.as_ref().map(|address| CString::new(address.inner.as_str())) {
+        Some(Ok(string)) => string.into_raw(),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Releases a handle returned by `eap_parse` or `eap_new`. Passing `NULL` is a no-op.
+///
+/// # Safety
+/// `address` must be `NULL` or a live handle, which must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn eap_email_address_free(address: *mut EapEmailAddress) {
+    if !address.is_null() {
+        drop(Box::from_raw(address));
+    }
+}
+
+/// Releases a string returned by `eap_email_address_to_string`. Passing `NULL` is a no-op.
+///
+/// # Safety
+/// `string` must be `NULL` or a string returned by this library, which must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn eap_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Returns a static, NUL-terminated description of `status`, an `EapStatus` value.
+///
+/// Takes an `int`, so that values outside of `EapStatus` are described as unknown.
+#[no_mangle]
+pub extern "C" fn eap_status_message(status: c_int) -> *const c_char {
+    let message: &'static [u8] = match status {
+        status if status == EapStatus::Ok as c_int => b"ok\0",
+        status if status == EapStatus::InvalidAddress as c_int => b"invalid email address\0",
+        status if status == EapStatus::NullPointer as c_int => b"null pointer argument\0",
+        status if status == EapStatus::InvalidUtf8 as c_int => b"invalid UTF-8 input\0",
+        _ => b"unknown status\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::slice;
+    use std::str;
+
+    unsafe fn as_str<'a>(value: EapStr) -> &'a str {
+        str::from_utf8(slice::from_raw_parts(value.ptr as *const u8, value.len)).unwrap()
+    }
+
+    #[test]
+    fn parse_exposes_components_and_frees() {
+        unsafe {
+            let mut address = ptr::null_mut();
+            let status = eap_parse(b"foo@bar.com\0".as_ptr() as *const c_char, ptr::null(), &mut address);
+            assert_eq!(status, EapStatus::Ok);
+            assert_eq!(as_str(eap_email_address_local_part(address)), "foo");
+            assert_eq!(as_str(eap_email_address_domain(address)), "bar.com");
+            assert_eq!(as_str(eap_email_address_as_str(address)), "foo@bar.com");
+            let string = eap_email_address_to_string(address);
+            assert_eq!(CStr::from_ptr(string).to_str().unwrap(), "foo@bar.com");
+            eap_string_free(string);
+            eap_email_address_free(address);
+        }
+    }
+
+    #[test]
+    fn reports_errors_without_writing_out() {
+        unsafe {
+            let mut address = ptr::null_mut();
+            let lax = EapParsingOptions { is_lax: true };
+            let input = b"test@-iana.org\0".as_ptr() as *const c_char;
+            assert_eq!(eap_parse(input, &lax, &mut address), EapStatus::InvalidAddress);
+            assert!(address.is_null());
+            assert_eq!(eap_parse(ptr::null(), ptr::null(), &mut address), EapStatus::NullPointer);
+            assert_eq!(eap_parse(input, ptr::null(), ptr::null_mut()), EapStatus::NullPointer);
+            let invalid_utf8 = b"f\xffo@bar.com\0".as_ptr() as *const c_char;
+            assert_eq!(eap_parse(invalid_utf8, ptr::null(), &mut address), EapStatus::InvalidUtf8);
+            assert!(!eap_is_valid(invalid_utf8, ptr::null()));
+            assert!(address.is_null());
+        }
+    }
+}
@@ -0,0 +1,542 @@
+//! Random generation of valid email addresses, by walking the grammar implemented in `nom_parser`.
+//!
+//! Every choice is drawn from a [`Choices`] source; running out of choices always picks the
+//! simplest alternative, so that shrinking the source (as `arbitrary` and `proptest` do) shrinks
+//! the address towards `a@a`.
+use crate::email_address::{EmailAddress, ParsingOptions};
+use alloc::string::String;
+
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const ATEXT_SPECIALS: &[u8] = b"!#$%&'*+/=?^_`{|}~";
+const UNICODE: &[char] = &['ö', 'ü', 'é', 'ß', 'ñ', 'ж', 'д', 'α', 'λ', '中', '日', 'ק', 'ب', 'ह', '😀', '𝔘'];
+const MAX_COMMENT_DEPTH: usize = 3;
+
+/// Generates random, valid email addresses with tunable features.
+///
+/// The generator mirrors the grammar of the parser, so that every generated address is valid with
+/// `ParsingOptions::new(is_lax)`, and `EmailAddress::parse(address, ..).unwrap().to_string() == address`.
+/// By default, all the optional features are enabled; disable them to get more conventional addresses.
+///
+/// Only available with the `arbitrary` or `proptest` feature.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+/// # #[cfg(feature = "arbitrary")]
+/// # {
+/// use arbitrary::Unstructured;
+///
+/// let generator = AddressGenerator::new(false);
+/// let address = generator.arbitrary_address(&mut Unstructured::new(&[7, 42, 3, 99, 18, 5])).unwrap();
+/// assert_eq!(EmailAddress::parse(&address, None).unwrap().to_string(), address);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AddressGenerator {
+    /// Also generates obsolete parts as defined by RFC 5322, which are only valid with lax parsing.
+    pub is_lax: bool,
+    /// Generates quoted local parts; e.g. `"foo bar"@example.com`.
+    pub quoted_strings: bool,
+    /// Generates comments; e.g. `foo(comment)@example.com`.
+    pub comments: bool,
+    /// Generates folding white space, including line breaks.
+    pub folding_whitespace: bool,
+    /// Generates domain literals; e.g. `foo@[192.168.0.1]`.
+    pub domain_literals: bool,
+    /// Generates non-ASCII characters, as allowed by RFC 6532.
+    pub unicode: bool,
+    /// The maximum number of dot-separated labels of dot-atoms; at least 1.
+    pub max_labels: usize,
+    /// The maximum number of characters of a label; at least 1.
+    pub max_label_len: usize,
+}
+
+impl AddressGenerator {
+    /// Instantiates a generator with every feature enabled, producing addresses valid with
+    /// `ParsingOptions::new(is_lax)`.
+    pub fn new(is_lax: bool) -> AddressGenerator {
+        AddressGenerator {
+            is_lax,
+            quoted_strings: true,
+            comments: true,
+            folding_whitespace: true,
+            domain_literals: true,
+            unicode: true,
+            max_labels: 4,
+            max_label_len: 12,
+        }
+    }
+
+    /// Returns the parsing options the generated addresses are valid with.
+    pub fn parsing_options(&self) -> ParsingOptions {
+        ParsingOptions::new(self.is_lax)
+    }
+
+    fn generate(&self, choices: &mut impl Choices) -> String {
+        let mut walker = Walker {
+            generator: self,
+            choices,
+            out: String::new(),
+        };
+        walker.address();
+        walker.out
+    }
+
+    fn to_email_address(&self, address: &str) -> EmailAddress {
+        EmailAddress::parse(address, Some(self.parsing_options()))
+            .unwrap_or_else(|| panic!("generated an invalid address: {:?}", address))
+    }
+}
+
+impl Default for AddressGenerator {
+    fn default() -> Self {
+        AddressGenerator::new(false)
+    }
+}
+
+/// Source of the random choices made while walking the grammar.
+trait Choices {
+    /// Returns a number in `0..bound`, or `0` if the source is exhausted.
+    fn below(&mut self, bound: usize) -> usize;
+}
+
+/// Choices read from raw bytes; `0` once exhausted.
+#[cfg(feature = "proptest")]
+struct ByteChoices<'a> {
+    bytes: &'a [u8],
+}
+
+#[cfg(feature = "proptest")]
+impl Choices for ByteChoices<'_> {
+    fn below(&mut self, bound: usize) -> usize {
+        if bound <= 1 {
+            return 0;
+        }
+        let width = if bound <= 1 << 8 {
+            1
+        } else if bound <= 1 << 16 {
+            2
+        } else {
+            4
+        };
+        let (taken, rest) = self.bytes.split_at(width.min(self.bytes.len()));
+        self.bytes = rest;
+        taken.iter().fold(0, |value, &byte| value << 8 | byte as usize) % bound
+    }
+}
+
+struct Walker<'g, 'c, C> {
+    generator: &'g AddressGenerator,
+    choices: &'c mut C,
+    out: String,
+}
+
+impl<C: Choices> Walker<'_, '_, C> {
+    /// `true` with a probability of `1 / n`; never once the choices are exhausted.
+    fn one_in(&mut self, n: usize) -> bool {
+        self.choices.below(n) == n - 1
+    }
+
+    /// Whether to add one more repetition; never once the choices are exhausted.
+    fn more(&mut self) -> bool {
+        self.choices.below(3) != 0
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.choices.below(items.len())]
+    }
+
+    // `address_spec = local_part "@" domain`
+    fn address(&mut self) {
+        self.local_part();
+        self.out.push('@');
+        self.domain();
+    }
+
+    // `local_part = dot_atom | quoted_string`, and `obs_local_part` for lax parsing.
+    fn local_part(&mut self) {
+        if self.generator.is_lax && self.one_in(3) {
+            self.obs_local_part();
+        } else if self.generator.quoted_strings && self.one_in(4) {
+            self.quoted_string();
+        } else {
+            self.dot_atom(false);
+        }
+    }
+
+    // `domain = dot_atom | domain_literal`, and `obs_domain` for lax parsing.
+    fn domain(&mut self) {
+        if self.generator.is_lax && self.one_in(3) {
+            self.obs_domain();
+        } else if self.generator.domain_literals && self.one_in(5) {
+            self.domain_literal();
+        } else {
+            self.dot_atom(true);
+        }
+    }
+
+    // `dot_atom = WSP? dot_atom_text WSP?`
+    fn dot_atom(&mut self, is_domain: bool) {
+        self.opt_wsp();
+        self.label(is_domain);
+        for _ in 1..self.generator.max_labels.max(1) {
+            if !self.more() {
+                break;
+            }
+            self.out.push('.');
+            // The parser allows CFWS after each '.'.
+            self.opt_cfws(8);
+            self.label(is_domain);
+        }
+        self.opt_wsp();
+    }
+
+    // A `dot_atom_text` label, without a leading or trailing '-'.
+    fn label(&mut self, is_domain: bool) {
+        let len = 1 + self.choices.below(self.generator.max_label_len.max(1));
+        for index in 0..len {
+            let ch = if index > 0 && index + 1 < len && self.one_in(6) {
+                '-'
+            } else {
+                self.atext_no_dash(is_domain)
+            };
+            self.out.push(ch);
+        }
+    }
+
+    fn atext_no_dash(&mut self, is_domain: bool) -> char {
+        if self.generator.unicode && self.one_in(8) {
+            self.unicode()
+        } else if !is_domain && self.one_in(8) {
+            self.pick(ATEXT_SPECIALS) as char
+        } else {
+            self.pick(ALPHANUMERIC) as char
+        }
+    }
+
+    fn unicode(&mut self) -> char {
+        if self.one_in(4) {
+            // Any non-ASCII scalar value is allowed.
+            let code = 0x80 + self.choices.below(0x11_0000 - 0x80) as u32;
+            char::from_u32(code).unwrap_or('\u{fffd}')
+        } else {
+            self.pick(UNICODE)
+        }
+    }
+
+    // `atext+`, as used by `obs_local_part`; '-' is allowed anywhere.
+    fn atext1(&mut self) {
+        let len = 1 + self.choices.below(self.generator.max_label_len.max(1));
+        for _ in 0..len {
+            let ch = if self.one_in(8) { '-' } else { self.atext_no_dash(false) };
+            self.out.push(ch);
+        }
+    }
+
+    // `atext_wo_dash+`, as used by `obs_domain`.
+    fn atext_no_dash1(&mut self) {
+        let len = 1 + self.choices.below(self.generator.max_label_len.max(1));
+        for _ in 0..len {
+            let ch = self.atext_no_dash(true);
+            self.out.push(ch);
+        }
+    }
+
+    fn opt_wsp(&mut self) {
+        if self.generator.folding_whitespace && self.one_in(10) {
+            let ch = self.pick(&[' ', '\t']);
+            self.out.push(ch);
+        }
+    }
+
+    /// Optional `FWS`, with a probability of `1 / n`; only with folding white space enabled.
+    fn opt_fws(&mut self, n: usize) {
+        if self.generator.folding_whitespace && self.one_in(n) {
+            self.fws();
+        }
+    }
+
+    /// Optional `CFWS`, with a probability of `1 / n`; only with comments or folding white space enabled.
+    fn opt_cfws(&mut self, n: usize) {
+        if (self.generator.comments || self.generator.folding_whitespace) && self.one_in(n) {
+            self.cfws(0);
+        }
+    }
+
+    // `FWS`; a single space when folding white space is disabled.
+    fn fws(&mut self) {
+        if !self.generator.folding_whitespace {
+            self.out.push(' ');
+            return;
+        }
+        let leading = self.choices.below(3);
+        for _ in 0..leading {
+            let ch = self.pick(&[' ', '\t']);
+            self.out.push(ch);
+        }
+        if leading == 0 || self.one_in(3) {
+            // A line break must be followed by at least one WSP.
+            self.out.push_str("\r\n");
+            let ch = self.pick(&[' ', '\t']);
+            self.out.push(ch);
+        }
+    }
+
+    // `CFWS = ((FWS? comment)+ FWS?) | FWS`
+    fn cfws(&mut self, depth: usize) {
+        if !self.generator.comments || !self.one_in(2) {
+            self.fws();
+            return;
+        }
+        loop {
+            if self.one_in(3) {
+                self.fws();
+            }
+            self.comment(depth);
+            if !self.one_in(3) {
+                break;
+            }
+        }
+        if self.one_in(2) {
+            self.fws();
+        }
+    }
+
+    // `comment = "(" (FWS? ccontent)* FWS? ")"`
+    fn comment(&mut self, depth: usize) {
+        self.out.push('(');
+        while self.more() {
+            if self.one_in(4) {
+                self.fws();
+            }
+            // `ccontent = ctext | quoted_pair | comment`
+            if depth + 1 < MAX_COMMENT_DEPTH && self.one_in(6) {
+                self.comment(depth + 1);
+            } else if self.one_in(6) {
+                self.quoted_pair();
+            } else {
+                let ch = self.text_char(&['(', ')', '\\']);
+                self.out.push(ch);
+            }
+        }
+        if self.one_in(4) {
+            self.fws();
+        }
+        self.out.push(')');
+    }
+
+    // `quoted_string = CFWS? DQUOTE (FWS? qcontent)* FWS? DQUOTE CFWS?`
+    fn quoted_string(&mut self) {
+        self.opt_cfws(6);
+        self.out.push('"');
+        while self.more() {
+            if self.one_in(4) {
+                self.fws();
+            }
+            // `qcontent = qtext | quoted_pair`
+            if self.one_in(6) {
+                self.quoted_pair();
+            } else {
+                let ch = self.text_char(&['"', '\\']);
+                self.out.push(ch);
+            }
+        }
+        if self.one_in(6) {
+            self.fws();
+        }
+        self.out.push('"');
+        self.opt_cfws(6);
+    }
+
+    // `quoted_pair`, including `obs_qp` for lax parsing.
+    fn quoted_pair(&mut self) {
+        self.out.push('\\');
+        let ch = if self.generator.is_lax && self.one_in(4) {
+            self.pick(&['\0', '\r', '\n', '\u{1}', '\u{7f}'])
+        } else if self.one_in(8) {
+            ' '
+        } else {
+            (0x21 + self.choices.below(0x7e - 0x21 + 1) as u8) as char
+        };
+        self.out.push(ch);
+    }
+
+    /// A `ctext`, `qtext` or `dtext` character; i.e. printable US-ASCII except `excluded`, or non-ASCII.
+    fn text_char(&mut self, excluded: &[char]) -> char {
+        if self.generator.unicode && self.one_in(8) {
+            return self.unicode();
+        }
+        if self.generator.is_lax && self.one_in(16) {
+            // `obs_NO_WS_CTL`
+            return self.pick(&['\u{1}', '\u{8}', '\u{b}', '\u{c}', '\u{e}', '\u{1f}', '\u{7f}']);
+        }
+        let ch = (0x21 + self.choices.below(0x7e - 0x21 + 1) as u8) as char;
+        if excluded.contains(&ch) {
+            'a'
+        } else {
+            ch
+        }
+    }
+
+    // `domain_literal = CFWS? "[" (FWS? dtext)* FWS? "]" CFWS?`
+    fn domain_literal(&mut self) {
+        self.opt_cfws(6);
+        self.out.push('[');
+        match self.choices.below(3) {
+            0 => {
+                let octets = [
+                    self.choices.below(256),
+                    self.choices.below(256),
+                    self.choices.below(256),
+                    self.choices.below(256),
+                ];
+                let literal = alloc::format!("{}.{}.{}.{}", octets[0], octets[1], octets[2], octets[3]);
+                self.out.push_str(&literal);
+            }
+            1 => {
+                let literal = alloc::format!("IPv6:2001:db8::{:x}", self.choices.below(0x1_0000));
+                self.out.push_str(&literal);
+            }
+            _ => {
+                while self.more() {
+                    if self.one_in(4) {
+                        self.fws();
+                    }
+                    let ch = self.text_char(&['[', ']', '\\']);
+                    self.out.push(ch);
+                }
+                if self.one_in(4) {
+                    self.fws();
+                }
+            }
+        }
+        self.out.push(']');
+        self.opt_cfws(6);
+    }
+
+    // `obs_local_part = FWS* word (CFWS* "." CFWS* word)*`
+    fn obs_local_part(&mut self) {
+        self.opt_fws(4);
+        self.word();
+        for _ in 1..self.generator.max_labels.max(1) {
+            if !self.more() {
+                break;
+            }
+            self.opt_cfws(4);
+            self.out.push('.');
+            self.opt_cfws(4);
+            self.word();
+        }
+    }
+
+    // `word = atom | quoted_string`, where `atom = CFWS? atext+ CFWS?`
+    fn word(&mut self) {
+        if self.generator.quoted_strings && self.one_in(3) {
+            self.quoted_string();
+            return;
+        }
+        self.opt_cfws(4);
+        self.atext1();
+        self.opt_cfws(4);
+    }
+
+    // `obs_domain = CFWS* atext_wo_dash+ (CFWS* ("." | "-"+) CFWS* atext_wo_dash+)* FWS*`
+    fn obs_domain(&mut self) {
+        self.opt_cfws(4);
+        self.atext_no_dash1();
+        for _ in 1..self.generator.max_labels.max(1) {
+            if !self.more() {
+                break;
+            }
+            // CFWS is only allowed around separators; trailing comments are not.
+            self.opt_cfws(4);
+            if self.one_in(2) {
+                for _ in 0..1 + self.choices.below(3) {
+                    self.out.push('-');
+                }
+            } else {
+                self.out.push('.');
+            }
+            self.opt_cfws(4);
+            self.atext_no_dash1();
+        }
+        self.opt_fws(4);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Choices for arbitrary::Unstructured<'a> {
+    fn below(&mut self, bound: usize) -> usize {
+        if bound <= 1 {
+            return 0;
+        }
+        self.int_in_range(0..=bound - 1).unwrap_or(0)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl AddressGenerator {
+    /// Generates a valid address from the data of `u`.
+    ///
+    /// Only available with the `arbitrary` feature.
+    pub fn arbitrary_address(&self, u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<String> {
+        Ok(self.generate(u))
+    }
+
+    /// Generates a valid `EmailAddress` from the data of `u`.
+    ///
+    /// Only available with the `arbitrary` feature.
+    pub fn arbitrary_email_address(&self, u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<EmailAddress> {
+        Ok(self.to_email_address(&self.generate(u)))
+    }
+}
+
+/// Generates addresses valid with strict parsing, using `AddressGenerator::default()`.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EmailAddress {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        AddressGenerator::default().arbitrary_email_address(u)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl AddressGenerator {
+    /// Returns a proptest strategy generating valid addresses; shrinking simplifies the address.
+    ///
+    /// Only available with the `proptest` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    /// # #[cfg(feature = "proptest")]
+    /// # {
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(address in AddressGenerator::new(true).strategy())| {
+    ///     let email = EmailAddress::parse(&address, Some(ParsingOptions::new(true))).unwrap();
+    ///     prop_assert_eq!(email.to_string(), address);
+    /// });
+    /// # }
+    /// ```
+    pub fn strategy(self) -> impl proptest::strategy::Strategy<Value = String> {
+        use proptest::prelude::*;
+        proptest::collection::vec(any::<u8>(), 0..512)
+            .prop_map(move |bytes| self.generate(&mut ByteChoices { bytes: &bytes }))
+    }
+}
+
+/// Generates addresses with the given `AddressGenerator`; strictly valid ones by default.
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for EmailAddress {
+    type Parameters = AddressGenerator;
+    type Strategy = proptest::strategy::BoxedStrategy<EmailAddress>;
+
+    fn arbitrary_with(generator: AddressGenerator) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        let parser = generator.clone();
+        generator
+            .strategy()
+            .prop_map(move |address| parser.to_email_address(&address))
+            .boxed()
+    }
+}
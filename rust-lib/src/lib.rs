@@ -17,6 +17,25 @@
 //! assert!(EmailAddress::parse("test@-iana.org", None).is_none());
 //! ```
 //!
+//! To find out why an input is not a valid email address, use `EmailAddress::try_parse`.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let error = EmailAddress::try_parse("foo@-bar.com", None).unwrap_err();
+//! assert_eq!(error.kind(), ParseErrorKind::InvalidDomain);
+//! assert_eq!(error.offset(), 4);
+//! ```
+//!
+//! To validate and inspect an address without allocating, use `EmailAddressRef`, which borrows from the input.
+//! ```
+//! use email_address_parser::EmailAddressRef;
+//!
+//! let email = EmailAddressRef::parse("foo@bar.com", None).unwrap();
+//! assert_eq!(email.get_domain(), "bar.com");
+//! let owned = email.to_owned();
+//! assert_eq!(owned.get_domain(), "bar.com");
+//! ```
+//!
 //! To parse an email address with obsolete parts (as per RFC 5322) in it, pass `Some(ParsingOptions::new(true))` to enable lax parsing.
 //! ```
 //! use email_address_parser::*;
@@ -25,6 +44,134 @@
 //! assert!(email.is_some());
 //! ```
 //!
+//! Raw octets, e.g. from mail headers, can be parsed with `EmailAddress::parse_bytes`, which reports invalid
+//! UTF-8 as `ParseErrorKind::InvalidUtf8`, or accepts 8-bit octets with lax parsing and `allow_8bit`.
+//!
+//! To find the components of an address in the input, e.g. to highlight them, use `EmailAddress::parse_with_spans`.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let (_, spans) = EmailAddress::parse_with_spans("foo@bar.com", None).unwrap();
+//! assert_eq!(spans.domain, Span { start: 4, end: 11 });
+//! ```
+//!
+//! ## Batch validation
+//! Large inputs can be validated with `EmailAddress::validate_many`, or line by line from a `BufRead` with `EmailAddress::validate_lines`.
+//! Enable the `rayon` feature for `EmailAddress::par_validate_many`.
+//! ```
+//! use email_address_parser::EmailAddress;
+//!
+//! let invalid: Vec<usize> = EmailAddress::validate_many(&["foo@bar.com", "foo@-bar.com"], None)
+//!     .filter(|result| !result.is_valid)
+//!     .map(|result| result.index)
+//!     .collect();
+//! assert_eq!(invalid, vec![1]);
+//! ```
+//!
+//! ## Generating addresses
+//! With the `arbitrary` or `proptest` feature enabled, `AddressGenerator` produces random, valid addresses
+//! for property tests and fuzzing, by walking the same grammar as the parser.
+//!
+//! ## Near misses
+//! `EmailAddress::near_misses` derives invalid addresses from a valid one (unbalanced quotes, stray `@`,
+//! hyphens at label edges, ...), each tagged with the `ParseErrorKind` it is expected to fail with.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let email = EmailAddress::parse("foo@bar.com", None).unwrap();
+//! for near_miss in email.near_misses(None) {
+//!     let error = EmailAddress::try_parse(&near_miss.input, None).unwrap_err();
+//!     assert_eq!(error.kind(), near_miss.expected_kind());
+//! }
+//! ```
+//!
+//! ## Deobfuscation
+//! `Deobfuscator` recovers addresses written as `john [at] example [dot] com`, `john at example dot com`, or with
+//! HTML character references, ranking the valid candidates by confidence.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let deobfuscated = EmailAddress::deobfuscate("john(at)example&#46;com", None).unwrap();
+//! assert_eq!(deobfuscated.email, "john@example.com");
+//! ```
+//!
+//! ## Domain typo suggestions
+//! `EmailAddress::suggest_corrections` suggests popular domains close to a mistyped one, offline; `DomainSuggester`
+//! takes other lists of domains and top-level domains.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let email = EmailAddress::parse("jane@gmial.com", None).unwrap();
+//! assert_eq!(email.suggest_corrections()[0].email, "jane@gmail.com");
+//! ```
+//!
+//! ## Role accounts
+//! `EmailAddress::role_kind` classifies local parts such as `postmaster`, `abuse`, or `noreply`, after unquoting,
+//! case-folding, and stripping a `+` subaddress; `RoleClassifier` takes other names.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let email = EmailAddress::parse("Abuse+spam@example.com", None).unwrap();
+//! assert_eq!(email.role_kind(), Some(RoleKind::NetworkOperations));
+//! ```
+//!
+//! ## Disposable domains
+//! With the `disposable` feature enabled, `EmailAddress::is_disposable` checks the domain, and the domains it is a
+//! subdomain of, against a list of disposable domains embedded at build time; `DisposableDomains` loads other lists.
+//!
+//! ## DNS deliverability
+//! With the `dns` feature enabled, `dns::check_deliverability` looks up the MX, A, and AAAA records of the domain
+//! through a `dns::Resolver`, and recognises null MX records; `dns::MockResolver` answers from memory, and
+//! `dns::SystemResolver`, with the `system-resolver` feature, asks the resolvers of the system.
+//!
+//! ## SMTP recipient verification
+//! With the `smtp-verify` feature enabled, `smtp_verify::SmtpVerifier` asks a mail server, over any `Read + Write`
+//! transport, whether it accepts a recipient, without sending mail; `smtp_verify::FakeServer` replies from a script.
+//!
+//! ## Regular expressions
+//! `RegexSource` writes the grammar as ECMAScript, PCRE, or `regex` crate expressions, e.g. for HTML `pattern` attributes.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let source = RegexSource::new(RegexDialect::Rust, false).address();
+//! let regex = regex::Regex::new(&format!("^(?:{})$", source)).unwrap();
+//! assert!(regex.is_match("foo@bar.com"));
+//! ```
+//!
+//! ## Display names
+//! `DisplayName` decodes the RFC 2047 encoded-words of a mailbox display name, and encodes non-ASCII names for 7-bit headers.
+//! ```
+//! use email_address_parser::*;
+//!
+//! assert_eq!(DisplayName::decode("=?UTF-8?B?SsO2cmc=?=").as_str(), "Jörg");
+//! assert_eq!(DisplayName::new("Jörg").encode(), "=?UTF-8?B?SsO2cmc=?=");
+//! ```
+//!
+//! ## `mailto` URIs
+//! `MailtoUri` parses [RFC 6068](https://tools.ietf.org/html/rfc6068) URIs, validating every address, and renders them percent-encoded.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let uri = MailtoUri::parse("mailto:a@x.com?cc=b@y.com&subject=Hi%20there", None).unwrap();
+//! assert_eq!(uri.cc, vec!["b@y.com"]);
+//! assert_eq!(uri.to_string(), "mailto:a@x.com?cc=b@y.com&subject=Hi%20there");
+//! ```
+//!
+//! ## `acct` URIs and fediverse handles
+//! `AcctUri` parses the [RFC 7565](https://tools.ietf.org/html/rfc7565) URIs of WebFinger, and `FediverseHandle`
+//! handles like `@alice@mastodon.social`; both validate their domain like `EmailAddress` does.
+//! ```
+//! use email_address_parser::*;
+//!
+//! let handle = FediverseHandle::parse("@alice@mastodon.social").unwrap();
+//! assert_eq!(handle.to_acct_uri(), AcctUri::parse("acct:alice@mastodon.social").unwrap());
+//! ```
+//!
+//! ## `no_std` support
+//! The crate builds under `#![no_std]` with `alloc` when the default `std` feature is disabled.
+//! Parsing, validation, and `EmailAddress::validate_many` remain available; `EmailAddress::validate_lines`,
+//! the `std::error::Error` implementations of `ParseError` and `UriError`, and the WASM bindings require `std`.
+//!
 //! ## Unicode support
 //! In compliance to [RFC 6532](https://tools.ietf.org/html/rfc6532), it supports parsing, validating, and instantiating email addresses with Unicode characters.
 //!
@@ -36,8 +183,57 @@
 //! assert!(EmailAddress::is_valid("foö@bücher.de", None));
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod acct;
+mod batch;
+mod cfws;
+mod deobfuscate;
+#[cfg(feature = "disposable")]
+mod disposable;
+#[cfg(feature = "dns")]
+pub mod dns;
 mod email_address;
+mod encoded_word;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod generator;
+mod mailto;
+mod near_miss;
 mod nom_parser;
+mod parse_error;
+mod percent_encoding;
+#[cfg(feature = "python")]
+mod python;
+mod regex_source;
+mod role;
+#[cfg(feature = "smtp-verify")]
+pub mod smtp_verify;
+mod spans;
+mod suggest;
+#[cfg(all(target_arch = "wasm32", feature = "std"))]
+mod wasm;
+pub use self::acct::{AcctUri, FediverseHandle};
+pub use self::batch::{ValidateMany, ValidationResult};
+#[cfg(feature = "std")]
+pub use self::batch::{LineValidationResult, ValidateLines};
+pub use self::deobfuscate::{Deobfuscated, Deobfuscator, Obfuscation, Substitution};
+#[cfg(feature = "disposable")]
+pub use self::disposable::DisposableDomains;
 #[doc(inline)]
 pub use self::email_address::EmailAddress;
+pub use self::email_address::EmailAddressRef;
 pub use self::email_address::ParsingOptions;
+pub use self::encoded_word::DisplayName;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub use self::generator::AddressGenerator;
+pub use self::mailto::MailtoUri;
+pub use self::near_miss::{Mutation, NearMiss};
+pub use self::parse_error::{ParseError, ParseErrorKind, UriError};
+pub use self::regex_source::{RegexDialect, RegexSource};
+pub use self::role::{RoleClassifier, RoleKind};
+pub use self::spans::{AddressSpans, Span};
+pub use self::suggest::{DomainSuggester, Suggestion};
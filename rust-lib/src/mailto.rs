@@ -0,0 +1,339 @@
+//! `mailto` URIs, as per [RFC 6068](https://tools.ietf.org/html/rfc6068).
+use crate::email_address::{EmailAddress, ParsingOptions};
+use crate::parse_error::{ParseError, UriError};
+use crate::percent_encoding::{is_unreserved, percent_decode, raw_offset, write_encoded};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+
+const SCHEME: &str = "mailto:";
+
+/// A `mailto` URI; e.g. `mailto:a@x.com,b@y.com?cc=c@z.com&subject=Hi%20there`.
+///
+/// [`MailtoUri::parse`] percent-decodes the URI, and parses every address of the `to` list and of
+/// the `to`, `cc`, and `bcc` header fields. [`Display`](fmt::Display) renders it back, percent-encoding
+/// quoted local parts, Unicode characters (as UTF-8), and the delimiters of the URI.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let uri = MailtoUri::parse("mailto:a@x.com,b@y.com?cc=c@z.com&subject=Hi%20there", None).unwrap();
+/// assert_eq!(uri.to, vec!["a@x.com", "b@y.com"]);
+/// assert_eq!(uri.cc, vec!["c@z.com"]);
+/// assert_eq!(uri.subject.as_deref(), Some("Hi there"));
+///
+/// let mut uri = MailtoUri::new(vec![EmailAddress::parse("\"John Doe\"@x.com", None).unwrap()]);
+/// uri.body = Some(String::from("Hello & goodbye"));
+/// assert_eq!(uri.to_string(), "mailto:%22John%20Doe%22@x.com?body=Hello%20%26%20goodbye");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MailtoUri {
+    /// The recipients, from the path of the URI followed by those of `to` header fields.
+    pub to: Vec<EmailAddress>,
+    /// The recipients of `cc` header fields.
+    pub cc: Vec<EmailAddress>,
+    /// The recipients of `bcc` header fields.
+    pub bcc: Vec<EmailAddress>,
+    /// The `subject` header field; the last one, if repeated.
+    pub subject: Option<String>,
+    /// The `body` pseudo header field, which is the text of the message; the last one, if repeated.
+    /// Line breaks are rendered as `%0D%0A`, as RFC 6068 requires.
+    pub body: Option<String>,
+    /// The other header fields, in order, with their names as written; e.g. `In-Reply-To`.
+    pub headers: Vec<(String, String)>,
+}
+
+impl MailtoUri {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Instantiates a `mailto` URI for the given recipients, without header fields.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let uri = MailtoUri::new(vec![EmailAddress::parse("foö@bücher.de", None).unwrap()]);
+    /// assert_eq!(uri.to_string(), "mailto:fo%C3%B6@b%C3%BCcher.de");
+    /// ```
+    pub fn new(to: Vec<EmailAddress>) -> MailtoUri {
+        MailtoUri {
+            to,
+            ..MailtoUri::default()
+        }
+    }
+
+    /// Parses a `mailto` URI, validating every address it contains with `options`.
+    ///
+    /// The scheme is case-insensitive, and so are the names of header fields. Addresses are
+    /// separated by commas outside quoted strings and comments, and may be surrounded by spaces.
+    ///
+    /// Returns a `UriError` with the byte offset into `input` at which the error was detected:
+    /// `UriError::Malformed` for malformed URIs, or `UriError::Invalid` with `ParseErrorKind::InvalidUtf8`
+    /// for percent-encoded octets that are not UTF-8, or with the kind of the first invalid address.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let uri = MailtoUri::parse("MAILTO:?To=%22a%2Cb%22@x.com&In-Reply-To=%3C1@x.com%3E", None).unwrap();
+    /// assert_eq!(uri.to, vec!["\"a,b\"@x.com"]);
+    /// assert_eq!(uri.headers, vec![(String::from("In-Reply-To"), String::from("<1@x.com>"))]);
+    ///
+    /// let error = MailtoUri::parse("mailto:a@x.com,b@-y.com", None).unwrap_err();
+    /// assert_eq!(error.parse_error().map(ParseError::kind), Some(ParseErrorKind::InvalidDomain));
+    /// assert_eq!(error.offset(), 17);
+    ///
+    /// let error = MailtoUri::parse("http://x.com", None).unwrap_err();
+    /// assert_eq!(error, UriError::Malformed(0));
+    /// ```
+    pub fn parse(input: &str, options: Option<ParsingOptions>) -> Result<MailtoUri, UriError> {
+        if !input.get(..SCHEME.len()).is_some_and(|scheme| scheme.eq_ignore_ascii_case(SCHEME)) {
+            return Err(UriError::Malformed(0));
+        }
+        // A `mailto` URI has no fragment.
+        if let Some(at) = input.find('#') {
+            return Err(UriError::Malformed(at));
+        }
+        let query = input.find('?').unwrap_or(input.len());
+        let mut uri = MailtoUri {
+            to: parse_addresses(input, SCHEME.len()..query, &options)?,
+            ..MailtoUri::default()
+        };
+        let mut start = query + 1;
+        while start <= input.len() {
+            let end = input[start..].find('&').map_or(input.len(), |at| start + at);
+            let field = &input[start..end];
+            if !field.is_empty() {
+                let equals = field
+                    .find('=')
+                    .map(|at| start + at)
+                    .ok_or(UriError::Malformed(end))?;
+                let name = percent_decode(input, start..equals)?;
+                let value = equals + 1..end;
+                if name.eq_ignore_ascii_case("to") {
+                    uri.to.extend(parse_addresses(input, value, &options)?);
+                } else if name.eq_ignore_ascii_case("cc") {
+                    uri.cc.extend(parse_addresses(input, value, &options)?);
+                } else if name.eq_ignore_ascii_case("bcc") {
+                    uri.bcc.extend(parse_addresses(input, value, &options)?);
+                } else if name.eq_ignore_ascii_case("subject") {
+                    uri.subject = Some(percent_decode(input, value)?);
+                } else if name.eq_ignore_ascii_case("body") {
+                    uri.body = Some(percent_decode(input, value)?);
+                } else {
+                    uri.headers.push((name, percent_decode(input, value)?));
+                }
+            }
+            start = end + 1;
+        }
+        Ok(uri)
+    }
+}
+
+impl FromStr for MailtoUri {
+    type Err = UriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MailtoUri::parse(s, None)
+    }
+}
+
+impl fmt::Display for MailtoUri {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(SCHEME)?;
+        write_addresses(formatter, &self.to)?;
+        let mut separator = '?';
+        let mut write_name = |formatter: &mut fmt::Formatter<'_>, name: &str| {
+            write!(formatter, "{}", separator)?;
+            separator = '&';
+            write_encoded(formatter, name, is_hfvalue_literal)?;
+            formatter.write_str("=")
+        };
+        for (name, addresses) in [("cc", &self.cc), ("bcc", &self.bcc)] {
+            if !addresses.is_empty() {
+                write_name(formatter, name)?;
+                write_addresses(formatter, addresses)?;
+            }
+        }
+        if let Some(subject) = &self.subject {
+            write_name(formatter, "subject")?;
+            write_encoded(formatter, subject, is_hfvalue_literal)?;
+        }
+        for (name, value) in &self.headers {
+            write_name(formatter, name)?;
+            write_encoded(formatter, value, is_hfvalue_literal)?;
+        }
+        if let Some(body) = &self.body {
+            write_name(formatter, "body")?;
+            let mut lines = body.split('\n').peekable();
+            while let Some(line) = lines.next() {
+                write_encoded(formatter, line.strip_suffix('\r').unwrap_or(line), is_hfvalue_literal)?;
+                if lines.peek().is_some() {
+                    formatter.write_str("%0D%0A")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the comma-separated addresses of `input[range]`, once percent-decoded.
+fn parse_addresses(
+    input: &str,
+    range: Range<usize>,
+    options: &Option<ParsingOptions>,
+) -> Result<Vec<EmailAddress>, UriError> {
+    let decoded = percent_decode(input, range.clone())?;
+    if decoded.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let raw = &input.as_bytes()[range.clone()];
+    split_addresses(&decoded)
+        .map(|(start, item)| {
+            let address = item.trim_matches(' ');
+            let start = start + item.len() - item.trim_start_matches(' ').len();
+            EmailAddress::try_parse(address, options.clone()).map_err(|error| {
+                let offset = range.start + raw_offset(raw, start + error.offset());
+                UriError::Invalid(ParseError::new(error.kind(), offset))
+            })
+        })
+        .collect()
+}
+
+/// Splits `list` at the commas outside quoted strings and comments, along with the offset of every item.
+fn split_addresses(list: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut starts = Vec::from([0]);
+    let (mut is_quoted, mut is_escaped, mut depth) = (false, false, 0usize);
+    for (index, ch) in list.char_indices() {
+        match ch {
+            _ if is_escaped => is_escaped = false,
+            '\\' => is_escaped = true,
+            '"' if depth == 0 => is_quoted = !is_quoted,
+            '(' if !is_quoted => depth += 1,
+            ')' if !is_quoted => depth = depth.saturating_sub(1),
+            ',' if !is_quoted && depth == 0 => starts.push(index + 1),
+            _ => {}
+        }
+    }
+    let ends: Vec<usize> = starts.iter().skip(1).map(|start| start - 1).chain([list.len()]).collect();
+    starts
+        .into_iter()
+        .zip(ends)
+        .map(move |(start, end)| (start, &list[start..end]))
+}
+
+fn write_addresses(formatter: &mut fmt::Formatter<'_>, addresses: &[EmailAddress]) -> fmt::Result {
+    for (index, address) in addresses.iter().enumerate() {
+        if index > 0 {
+            formatter.write_str(",")?;
+        }
+        write_encoded(formatter, address.as_str(), is_address_literal)?;
+    }
+    Ok(())
+}
+
+// `qchar` of RFC 6068: unreserved characters and `some-delims`.
+fn is_hfvalue_literal(octet: u8) -> bool {
+    is_unreserved(octet) || b"!$'()*+,;:@".contains(&octet)
+}
+
+// Commas separate addresses, and RFC 6068 requires `;` to be percent-encoded in addresses.
+fn is_address_literal(octet: u8) -> bool {
+    is_hfvalue_literal(octet) && octet != b',' && octet != b';'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_error::ParseErrorKind;
+
+    fn parse(input: &str) -> MailtoUri {
+        MailtoUri::parse(input, None).unwrap()
+    }
+
+    #[test]
+    fn parses_rfc_6068_examples() {
+        let uri = parse("mailto:chris@example.com");
+        assert_eq!(uri.to, vec!["chris@example.com"]);
+
+        let uri = parse("mailto:infobot@example.com?subject=current-issue");
+        assert_eq!(uri.subject.as_deref(), Some("current-issue"));
+
+        let uri = parse("mailto:infobot@example.com?body=send%20current-issue%0D%0Asend%20index");
+        assert_eq!(uri.body.as_deref(), Some("send current-issue\r\nsend index"));
+
+        let uri = parse("mailto:list@example.org?In-Reply-To=%3C3469A91.D10AF4C@example.com%3E");
+        assert_eq!(uri.headers, vec![(String::from("In-Reply-To"), String::from("<3469A91.D10AF4C@example.com>"))]);
+
+        let uri = parse("mailto:?to=joe@example.com&cc=bob@example.com&body=hello");
+        assert_eq!(uri.to, vec!["joe@example.com"]);
+        assert_eq!(uri.cc, vec!["bob@example.com"]);
+
+        let uri = parse("mailto:joe@example.com?cc=bob@example.com&bcc=a@x.com,%20b@x.com");
+        assert_eq!(uri.bcc, vec!["a@x.com", "b@x.com"]);
+
+        let uri = parse("mailto:%22not%40me%22@example.org");
+        assert_eq!(uri.to, vec!["\"not@me\"@example.org"]);
+
+        let uri = parse("mailto:%22%5C%5C%22@example.org");
+        assert_eq!(uri.to, vec!["\"\\\\\"@example.org"]);
+
+        let uri = parse("mailto:user@%E7%B4%8D%E8%B1%86.example.org?subject=Test&body=NATTO");
+        assert_eq!(uri.to, vec!["user@納豆.example.org"]);
+    }
+
+    #[test]
+    fn reports_offsets_into_the_uri() {
+        let invalid = |kind, offset| UriError::Invalid(ParseError::new(kind, offset));
+        let cases = [
+            ("mailto", UriError::Malformed(0)),
+            ("mailto:a@x.com#top", UriError::Malformed(14)),
+            ("mailto:a@x.com?subject", UriError::Malformed(22)),
+            ("mailto:a@x.com?subject=%2", UriError::Malformed(23)),
+            ("mailto:a@x.com?subject=%FF", invalid(ParseErrorKind::InvalidUtf8, 23)),
+            ("mailto:a@x.com,", invalid(ParseErrorKind::Empty, 15)),
+            ("mailto:%22a%22%22@x.com", invalid(ParseErrorKind::InvalidLocalPart, 14)),
+            ("mailto:a@x.com?cc=b@x.com,%20c@-x.com", invalid(ParseErrorKind::InvalidDomain, 31)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(MailtoUri::parse(input, None).unwrap_err(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn renders_percent_encoded() {
+        let address = |input| EmailAddress::parse(input, Some(ParsingOptions::new(true))).unwrap();
+        let uri = MailtoUri {
+            to: vec![address("\"a,b;c\"@x.com"), address("jörg@bücher.de")],
+            cc: vec![address("c@x.com")],
+            bcc: vec![],
+            subject: Some(String::from("50% off?")),
+            body: Some(String::from("line 1\nline 2\r\nline=3&4#")),
+            headers: vec![(String::from("X=Y"), String::from("a&b"))],
+        };
+        let rendered = uri.to_string();
+        assert_eq!(
+            rendered,
+            "mailto:%22a%2Cb%3Bc%22@x.com,j%C3%B6rg@b%C3%BCcher.de?cc=c@x.com&subject=50%25%20off%3F\
+             &X%3DY=a%26b&body=line%201%0D%0Aline%202%0D%0Aline%3D3%264%23"
+        );
+        let parsed = parse(&rendered);
+        assert_eq!(parsed.body.as_deref(), Some("line 1\r\nline 2\r\nline=3&4#"));
+        assert_eq!(MailtoUri { body: uri.body.clone(), ..parsed }, uri);
+    }
+
+    #[test]
+    fn splits_addresses_outside_quotes_and_comments() {
+        let uri = MailtoUri::parse("mailto:a(x,y)@x.com,%22b,%5C%22%22@x.com", Some(ParsingOptions::new(true))).unwrap();
+        assert_eq!(uri.to, vec!["a(x,y)@x.com", "\"b,\\\"\"@x.com"]);
+        assert_eq!(parse("mailto:"), MailtoUri::default());
+        assert_eq!(parse("mailto:a@x.com?&to=&subject=&"), MailtoUri {
+            subject: Some(String::new()),
+            ..MailtoUri::new(vec![EmailAddress::parse("a@x.com", None).unwrap()])
+        });
+    }
+}
@@ -0,0 +1,247 @@
+use crate::email_address::{EmailAddress, ParsingOptions};
+use crate::nom_parser;
+use crate::parse_error::ParseErrorKind;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A systematic mutation that turns a valid address into an invalid one.
+///
+/// Used by [`EmailAddress::near_misses`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mutation {
+    /// A `"` is prepended to the local part; e.g. `"foo@bar.com`.
+    UnbalancedQuote,
+    /// A `\` is appended to the local part; e.g. `foo\@bar.com`.
+    DanglingBackslash,
+    /// A `(` is appended to the local part; e.g. `foo(@bar.com`.
+    UnbalancedComment,
+    /// An unquoted space and another word are appended to the local part; e.g. `foo x@bar.com`.
+    UnquotedSpace,
+    /// A carriage return without a line feed precedes the `@`; e.g. `foo\r@bar.com`.
+    BareCarriageReturn,
+    /// The local part starts with a `.`; e.g. `.foo@bar.com`.
+    LocalPartLeadingDot,
+    /// The local part ends with a `.`; e.g. `foo.@bar.com`.
+    LocalPartTrailingDot,
+    /// The local part contains `..`; e.g. `f..oo@bar.com`.
+    LocalPartDoubleDot,
+    /// The local part is empty; e.g. `@bar.com`.
+    EmptyLocalPart,
+    /// The `@` and the domain are missing; e.g. `foo`.
+    MissingAt,
+    /// A second `@` follows the first one; e.g. `foo@@bar.com`.
+    StrayAt,
+    /// The domain is empty; e.g. `foo@`.
+    EmptyDomain,
+    /// The domain starts with a `.`; e.g. `foo@.bar.com`.
+    DomainLeadingDot,
+    /// The domain ends with a `.`; e.g. `foo@bar.com.`.
+    DomainTrailingDot,
+    /// The domain contains `..`; e.g. `foo@bar..com`.
+    DomainDoubleDot,
+    /// The first domain label starts with a `-`; e.g. `foo@-bar.com`.
+    LabelLeadingHyphen,
+    /// The first domain label ends with a `-`; e.g. `foo@bar-.com`.
+    LabelTrailingHyphen,
+    /// A `[` is prepended to the domain; e.g. `foo@[bar.com`.
+    UnbalancedBracket,
+}
+
+impl Mutation {
+    /// Every mutation, in the order they are applied by [`EmailAddress::near_misses`].
+    const ALL: [Mutation; 18] = [
+        Mutation::UnbalancedQuote,
+        Mutation::DanglingBackslash,
+        Mutation::UnbalancedComment,
+        Mutation::UnquotedSpace,
+        Mutation::BareCarriageReturn,
+        Mutation::LocalPartLeadingDot,
+        Mutation::LocalPartTrailingDot,
+        Mutation::LocalPartDoubleDot,
+        Mutation::EmptyLocalPart,
+        Mutation::MissingAt,
+        Mutation::StrayAt,
+        Mutation::EmptyDomain,
+        Mutation::DomainLeadingDot,
+        Mutation::DomainTrailingDot,
+        Mutation::DomainDoubleDot,
+        Mutation::LabelLeadingHyphen,
+        Mutation::LabelTrailingHyphen,
+        Mutation::UnbalancedBracket,
+    ];
+
+    /// Returns the kind of error [`EmailAddress::try_parse`] reports for the mutated address,
+    /// with strict and lax parsing alike.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// assert_eq!(Mutation::StrayAt.expected_kind(), ParseErrorKind::InvalidDomain);
+    /// ```
+    pub fn expected_kind(&self) -> ParseErrorKind {
+        match self {
+            Mutation::UnbalancedQuote
+            | Mutation::DanglingBackslash
+            | Mutation::UnbalancedComment
+            | Mutation::UnquotedSpace
+            | Mutation::BareCarriageReturn
+            | Mutation::LocalPartLeadingDot
+            | Mutation::LocalPartTrailingDot
+            | Mutation::LocalPartDoubleDot
+            | Mutation::EmptyLocalPart => ParseErrorKind::InvalidLocalPart,
+            Mutation::MissingAt => ParseErrorKind::MissingAt,
+            Mutation::StrayAt
+            | Mutation::EmptyDomain
+            | Mutation::DomainLeadingDot
+            | Mutation::DomainTrailingDot
+            | Mutation::DomainDoubleDot
+            | Mutation::LabelLeadingHyphen
+            | Mutation::LabelTrailingHyphen
+            | Mutation::UnbalancedBracket => ParseErrorKind::InvalidDomain,
+        }
+    }
+
+    /// Applies the mutation to an address made of `local_part` and `domain`.
+    fn apply(&self, local_part: &str, domain: &str) -> String {
+        match self {
+            Mutation::UnbalancedQuote => format!("\"{}@{}", local_part, domain),
+            Mutation::DanglingBackslash => format!("{}\\@{}", local_part, domain),
+            Mutation::UnbalancedComment => format!("{}(@{}", local_part, domain),
+            Mutation::UnquotedSpace => format!("{} x@{}", local_part, domain),
+            Mutation::BareCarriageReturn => format!("{}\r@{}", local_part, domain),
+            Mutation::LocalPartLeadingDot => format!(".{}@{}", local_part, domain),
+            Mutation::LocalPartTrailingDot => format!("{}.@{}", local_part, domain),
+            Mutation::LocalPartDoubleDot => format!("{}@{}", double_dot(local_part), domain),
+            Mutation::EmptyLocalPart => format!("@{}", domain),
+            Mutation::MissingAt => String::from(local_part),
+            Mutation::StrayAt => format!("{}@@{}", local_part, domain),
+            Mutation::EmptyDomain => format!("{}@", local_part),
+            Mutation::DomainLeadingDot => format!("{}@.{}", local_part, domain),
+            Mutation::DomainTrailingDot => format!("{}@{}.", local_part, domain),
+            Mutation::DomainDoubleDot => format!("{}@{}", local_part, double_dot(domain)),
+            Mutation::LabelLeadingHyphen => format!("{}@-{}", local_part, domain),
+            Mutation::LabelTrailingHyphen => {
+                let end = domain.find('.').unwrap_or(domain.len());
+                format!("{}@{}-{}", local_part, &domain[..end], &domain[end..])
+            }
+            Mutation::UnbalancedBracket => format!("{}@[{}", local_part, domain),
+        }
+    }
+}
+
+/// Doubles the first `.` of `input`, or inserts `..` after its first character if it has none.
+fn double_dot(input: &str) -> String {
+    let at = match input.find('.') {
+        Some(dot) => dot,
+        None => input.chars().next().map_or(0, char::len_utf8),
+    };
+    format!("{}..{}", &input[..at], input[at..].strip_prefix('.').unwrap_or(&input[at..]))
+}
+
+/// An invalid address derived from a valid one by a single [`Mutation`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NearMiss {
+    /// The invalid address.
+    pub input: String,
+    /// The mutation that made the address invalid.
+    pub mutation: Mutation,
+}
+
+impl NearMiss {
+    /// Returns the kind of error [`EmailAddress::try_parse`] reports for `input`.
+    pub fn expected_kind(&self) -> ParseErrorKind {
+        self.mutation.expected_kind()
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Returns invalid addresses that differ from this one by a single, systematic mutation,
+    /// e.g. to test that a form rejects them with a proper message.
+    ///
+    /// Only the mutations that make the address invalid with the given `options` are returned;
+    /// for example, a domain literal is still valid with an extra `-` inside.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("foo@bar.com", None).unwrap();
+    /// let near_misses = email.near_misses(None);
+    /// assert!(near_misses.iter().any(|near_miss| near_miss.input == "foo@bar-.com"));
+    /// for near_miss in near_misses {
+    ///     let error = EmailAddress::try_parse(&near_miss.input, None).unwrap_err();
+    ///     assert_eq!(error.kind(), near_miss.expected_kind(), "{:?}", near_miss.mutation);
+    /// }
+    /// ```
+    pub fn near_misses(&self, options: Option<ParsingOptions>) -> Vec<NearMiss> {
+        let is_lax = options.unwrap_or_default().is_lax;
+        Mutation::ALL
+            .iter()
+            .map(|mutation| NearMiss {
+                input: mutation.apply(self.get_local_part(), self.get_domain()),
+                mutation: *mutation,
+            })
+            .filter(|near_miss| nom_parser::parse_address(&near_miss.input, is_lax).is_none())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutates_a_plain_address_in_every_way() {
+        let email = EmailAddress::parse("foo.bar@example.com", None).unwrap();
+        let near_misses = email.near_misses(None);
+        let inputs: Vec<&str> = near_misses.iter().map(|near_miss| near_miss.input.as_str()).collect();
+        assert_eq!(
+            inputs,
+            vec![
+                "\"foo.bar@example.com",
+                "foo.bar\\@example.com",
+                "foo.bar(@example.com",
+                "foo.bar x@example.com",
+                "foo.bar\r@example.com",
+                ".foo.bar@example.com",
+                "foo.bar.@example.com",
+                "foo..bar@example.com",
+                "@example.com",
+                "foo.bar",
+                "foo.bar@@example.com",
+                "foo.bar@",
+                "foo.bar@.example.com",
+                "foo.bar@example.com.",
+                "foo.bar@example..com",
+                "foo.bar@-example.com",
+                "foo.bar@example-.com",
+                "foo.bar@[example.com",
+            ]
+        );
+        for near_miss in near_misses {
+            let error = EmailAddress::try_parse(&near_miss.input, None).unwrap_err();
+            assert_eq!(error.kind(), near_miss.expected_kind(), "{:?}", near_miss);
+        }
+    }
+
+    #[test]
+    fn skips_mutations_that_remain_valid() {
+        let email = EmailAddress::parse("\"foo\"@[127.0.0.1]", None).unwrap();
+        let mutations: Vec<Mutation> = email.near_misses(None).iter().map(|near_miss| near_miss.mutation).collect();
+        assert!(!mutations.contains(&Mutation::LocalPartDoubleDot));
+        assert!(!mutations.contains(&Mutation::LabelTrailingHyphen));
+        assert!(mutations.contains(&Mutation::UnbalancedQuote));
+
+        let lax = Some(ParsingOptions::new(true));
+        let near_misses = EmailAddress::parse("foo@bar.com", None).unwrap().near_misses(lax.clone());
+        for near_miss in near_misses {
+            let error = EmailAddress::try_parse(&near_miss.input, lax.clone()).unwrap_err();
+            assert_eq!(error.kind(), near_miss.expected_kind(), "{:?}", near_miss);
+        }
+    }
+}
@@ -4,7 +4,10 @@ use nom::combinator::{all_consuming, consumed};
 use nom::error::{Error, ErrorKind};
 use nom::IResult;
 
+use crate::parse_error::{ParseError, ParseErrorKind};
+
 type Res<'a, T> = IResult<&'a str, T>;
+type Capture = for<'a> fn(&'a str) -> Res<'a, &'a str>;
 
 const MAX_RECURSION_DEPTH: usize = 128;
 
@@ -21,6 +24,79 @@ pub(crate) fn parse_address<'a>(input: &'a str, is_lax: bool) -> Option<(&'a str
     None
 }
 
+// Explains why `parse_address` rejected `input`; only meant to be called after a failed parse.
+// Every combination of the `local_part` and `domain` alternatives is tried, and the error that
+// made the furthest progress into the input is reported (ties go to the later component).
+pub(crate) fn diagnose_address(input: &str, is_lax: bool) -> ParseError {
+    if input.is_empty() {
+        return ParseError::new(ParseErrorKind::Empty, 0);
+    }
+
+    let (local_parts, domains): (&[Capture], &[Capture]) = if is_lax {
+        (
+            &[obs_local_part, dot_atom, quoted_string],
+            &[obs_domain, dot_atom, domain_literal],
+        )
+    } else {
+        (&[dot_atom, quoted_string], &[dot_atom, domain_literal])
+    };
+
+    let mut furthest = ParseError::new(ParseErrorKind::InvalidLocalPart, 0);
+    let mut record = |error: ParseError| {
+        if (error.offset(), error.kind() as u8) > (furthest.offset(), furthest.kind() as u8) {
+            furthest = error;
+        }
+    };
+
+    for local_part in local_parts {
+        let rest = match local_part(input) {
+            Ok((rest, _)) => rest,
+            Err(error) => {
+                record(ParseError::new(ParseErrorKind::InvalidLocalPart, error_offset(input, error)));
+                continue;
+            }
+        };
+        let at = input.len() - rest.len();
+        let Some(after_at) = rest.strip_prefix('@') else {
+            let kind = if rest.is_empty() { ParseErrorKind::MissingAt } else { ParseErrorKind::InvalidLocalPart };
+            record(ParseError::new(kind, at));
+            continue;
+        };
+        for domain in domains {
+            match domain(after_at) {
+                Ok(("", _)) => {}
+                Ok((rest, _)) => record(ParseError::new(ParseErrorKind::InvalidDomain, input.len() - rest.len())),
+                Err(error) => record(ParseError::new(
+                    ParseErrorKind::InvalidDomain,
+                    at + 1 + error_offset(after_at, error),
+                )),
+            }
+        }
+    }
+
+    furthest
+}
+
+// Entry point for domains outside of addresses (`acct` URIs, fediverse handles), with the
+// alternatives of `domain_strict`; on failure, returns the furthest offset that either reached.
+pub(crate) fn parse_domain(input: &str) -> Result<(), usize> {
+    let mut furthest = 0;
+    for domain in [dot_atom as Capture, domain_literal] {
+        match all_consuming(domain)(input) {
+            Ok(_) => return Ok(()),
+            Err(error) => furthest = furthest.max(error_offset(input, error)),
+        }
+    }
+    Err(furthest)
+}
+
+fn error_offset(input: &str, error: nom::Err<Error<&str>>) -> usize {
+    match error {
+        nom::Err::Error(error) | nom::Err::Failure(error) => input.len() - error.input.len(),
+        nom::Err::Incomplete(_) => input.len(),
+    }
+}
+
 #[cfg(test)]
 pub(crate) fn test_parse_domain_complete(input: &str) -> bool {
     all_consuming(domain_strict)(input).is_ok()
@@ -121,17 +197,19 @@ fn dot_atom_text(input: &str) -> Res<'_, ()> {
 fn dot_atom_label(input: &str) -> Res<'_, ()> {
     let (mut input, first) = take_char_if(input, is_atext_no_dash)?;
     let mut last = first;
+    let mut before_last = input;
 
     while let Some((ch, rest)) = next_char(input) {
         if !is_atext(ch) {
             break;
         }
         last = ch;
+        before_last = input;
         input = rest;
     }
 
     if last == '-' {
-        return fail(input);
+        return fail(before_last);
     }
 
     Ok((input, ()))
@@ -609,7 +687,7 @@ fn is_ctext_char(ch: char) -> bool {
         && (is_printable_us_ascii(ch) || is_utf8_non_ascii(ch) || is_obs_no_ws_ctl(ch))
 }
 
-fn is_qtext_char(ch: char) -> bool {
+pub(crate) fn is_qtext_char(ch: char) -> bool {
     ch != '"'
         && ch != '\\'
         && (is_printable_us_ascii(ch) || is_utf8_non_ascii(ch) || is_obs_no_ws_ctl(ch))
@@ -622,7 +700,7 @@ fn is_dtext_char(ch: char) -> bool {
         && (is_printable_us_ascii(ch) || is_utf8_non_ascii(ch) || is_obs_no_ws_ctl(ch))
 }
 
-fn is_atext(ch: char) -> bool {
+pub(crate) fn is_atext(ch: char) -> bool {
     ch.is_ascii_alphanumeric()
         || is_utf8_non_ascii(ch)
         || matches!(
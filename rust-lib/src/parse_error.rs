@@ -0,0 +1,156 @@
+use core::fmt;
+
+/// The reason why an input is not a valid email address.
+///
+/// New kinds may be added in minor releases; matches on it need a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input is empty.
+    Empty,
+    /// The local part, before the `@`, is invalid.
+    InvalidLocalPart,
+    /// The local part is not followed by an `@`.
+    MissingAt,
+    /// The domain, after the `@`, is invalid.
+    InvalidDomain,
+    /// The input is not valid UTF-8; only reported by `EmailAddress::parse_bytes`, and for
+    /// percent-encoded octets by `MailtoUri::parse` and `AcctUri::parse`.
+    InvalidUtf8,
+}
+
+impl ParseErrorKind {
+    /// Returns a short, human-readable description of the kind.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::ParseErrorKind;
+    ///
+    /// assert_eq!(ParseErrorKind::MissingAt.description(), "missing '@'");
+    /// ```
+    pub fn description(&self) -> &'static str {
+        match self {
+            ParseErrorKind::Empty => "empty input",
+            ParseErrorKind::InvalidLocalPart => "invalid local part",
+            ParseErrorKind::MissingAt => "missing '@'",
+            ParseErrorKind::InvalidDomain => "invalid domain",
+            ParseErrorKind::InvalidUtf8 => "invalid UTF-8",
+        }
+    }
+}
+
+/// Error returned by [`EmailAddress::try_parse`](crate::EmailAddress::try_parse) and
+/// [`EmailAddress::parse_bytes`](crate::EmailAddress::parse_bytes).
+///
+/// Carries the kind of the error, and the byte offset into the input at which the parser
+/// could not make further progress.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let error = EmailAddress::try_parse("foo@-bar.com", None).unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidDomain);
+/// assert_eq!(error.offset(), 4);
+/// assert_eq!(error.to_string(), "invalid domain at offset 4");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, offset: usize) -> Self {
+        ParseError { kind, offset }
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset into the input at which the error was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(formatter, "{} at offset {}", self.kind.description(), self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Error returned by [`MailtoUri::parse`](crate::MailtoUri::parse) and
+/// [`AcctUri::parse`](crate::AcctUri::parse).
+///
+/// Either the URI itself is malformed, or one of the addresses, or parts of addresses, it holds is
+/// invalid. Both carry the byte offset into the URI at which the error was detected.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let error = MailtoUri::parse("http://x.com", None).unwrap_err();
+/// assert_eq!(error, UriError::Malformed(0));
+/// assert_eq!(error.to_string(), "malformed URI at offset 0");
+///
+/// let error = MailtoUri::parse("mailto:a@-x.com", None).unwrap_err();
+/// assert_eq!(error.parse_error().map(ParseError::kind), Some(ParseErrorKind::InvalidDomain));
+/// assert_eq!(error.offset(), 9);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UriError {
+    /// The URI is malformed at the given offset; e.g. it has another scheme, or malformed percent-encoding.
+    Malformed(usize),
+    /// An address, or a part of one, is invalid; the offset of the error refers to the URI.
+    Invalid(ParseError),
+}
+
+impl UriError {
+    /// Returns the byte offset into the URI at which the error was detected.
+    pub fn offset(&self) -> usize {
+        match self {
+            UriError::Malformed(offset) => *offset,
+            UriError::Invalid(error) => error.offset(),
+        }
+    }
+
+    /// Returns the error of the invalid address, or part of one, if any.
+    pub fn parse_error(&self) -> Option<&ParseError> {
+        match self {
+            UriError::Malformed(_) => None,
+            UriError::Invalid(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseError> for UriError {
+    fn from(error: ParseError) -> Self {
+        UriError::Invalid(error)
+    }
+}
+
+impl fmt::Display for UriError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            UriError::Malformed(offset) => write!(formatter, "malformed URI at offset {}", offset),
+            UriError::Invalid(error) => error.fmt(formatter),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UriError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UriError::Malformed(_) => None,
+            UriError::Invalid(error) => Some(error),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Percent-encoding of URIs, as per [RFC 3986](https://tools.ietf.org/html/rfc3986#section-2.1).
+use crate::parse_error::{ParseError, ParseErrorKind, UriError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+// `unreserved = ALPHA / DIGIT / "-" / "." / "_" / "~"`
+pub(crate) fn is_unreserved(octet: u8) -> bool {
+    octet.is_ascii_alphanumeric() || matches!(octet, b'-' | b'.' | b'_' | b'~')
+}
+
+// `sub-delims = "!" / "$" / "&" / "'" / "(" / ")" / "*" / "+" / "," / ";" / "="`
+pub(crate) fn is_sub_delim(octet: u8) -> bool {
+    b"!$&'()*+,;=".contains(&octet)
+}
+
+/// Percent-decodes `input[range]` as UTF-8; errors carry offsets into `input`.
+pub(crate) fn percent_decode(input: &str, range: Range<usize>) -> Result<String, UriError> {
+    let raw = &input.as_bytes()[range.clone()];
+    let mut octets = Vec::with_capacity(raw.len());
+    let mut index = 0;
+    while index < raw.len() {
+        if raw[index] == b'%' {
+            let octet = raw
+                .get(index + 1..index + 3)
+                .and_then(|hex| Some(hex_value(hex[0])? << 4 | hex_value(hex[1])?))
+                .ok_or(UriError::Malformed(range.start + index))?;
+            octets.push(octet);
+            index += 3;
+        } else {
+            octets.push(raw[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(octets).map_err(|error| {
+        let offset = range.start + raw_offset(raw, error.utf8_error().valid_up_to());
+        UriError::Invalid(ParseError::new(ParseErrorKind::InvalidUtf8, offset))
+    })
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    char::from(digit).to_digit(16).map(|value| value as u8)
+}
+
+/// Converts a byte offset into the percent-decoded `raw` to one into `raw`.
+pub(crate) fn raw_offset(raw: &[u8], decoded_offset: usize) -> usize {
+    let mut offset = 0;
+    for _ in 0..decoded_offset {
+        offset += if raw[offset] == b'%' { 3 } else { 1 };
+    }
+    offset
+}
+
+/// Writes `text`, percent-encoding every octet for which `is_literal` is `false`.
+pub(crate) fn write_encoded(formatter: &mut fmt::Formatter<'_>, text: &str, is_literal: fn(u8) -> bool) -> fmt::Result {
+    for &octet in text.as_bytes() {
+        if is_literal(octet) {
+            write!(formatter, "{}", char::from(octet))?;
+        } else {
+            let hex = [b'%', HEX[usize::from(octet >> 4)], HEX[usize::from(octet & 0xf)]];
+            formatter.write_str(core::str::from_utf8(&hex).unwrap())?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use crate::email_address::{EmailAddress, ParsingOptions};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Options for parsing.
+///
+/// `is_lax` can be set to `True` or `False` to enable/disable obsolete parts parsing.
+/// The default is `False`.
+#[pyclass(name = "ParsingOptions", module = "email_address_parser", frozen, eq, skip_from_py_object)]
+#[derive(Clone, PartialEq)]
+struct PyParsingOptions {
+    #[pyo3(get)]
+    is_lax: bool,
+}
+
+#[pymethods]
+impl PyParsingOptions {
+    #[new]
+    #[pyo3(signature = (is_lax = false))]
+    fn new(is_lax: bool) -> Self {
+        PyParsingOptions { is_lax }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ParsingOptions(is_lax={})", if self.is_lax { "True" } else { "False" })
+    }
+}
+
+impl From<&PyParsingOptions> for ParsingOptions {
+    fn from(options: &PyParsingOptions) -> Self {
+        ParsingOptions::new(options.is_lax)
+    }
+}
+
+fn to_options(options: Option<PyRef<'_, PyParsingOptions>>) -> Option<ParsingOptions> {
+    options.map(|options| ParsingOptions::from(&*options))
+}
+
+/// An RFC 5322, and RFC 6532 compliant email address.
+#[pyclass(name = "EmailAddress", module = "email_address_parser", frozen, eq, hash)]
+#[derive(PartialEq, Hash)]
+struct PyEmailAddress {
+    inner: EmailAddress,
+}
+
+#[pymethods]
+impl PyEmailAddress {
+    /// Instantiates a new email address from a local part and a domain.
+    ///
+    /// Raises `ValueError` if either of them is invalid.
+    #[new]
+    #[pyo3(signature = (local_part, domain, options = None))]
+    fn new(
+        local_part: &str,
+        domain: &str,
+        options: Option<PyRef<'_, PyParsingOptions>>,
+    ) -> PyResult<Self> {
+        EmailAddress::new(local_part, domain, to_options(options))
+            .map(|inner| PyEmailAddress { inner })
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Parses a given string as an email address.
+    ///
+    /// Returns an `EmailAddress` if the parsing is successful, else `None`.
+    #[staticmethod]
+    #[pyo3(signature = (input, options = None))]
+    fn parse(input: &str, options: Option<PyRef<'_, PyParsingOptions>>) -> Option<Self> {
+        EmailAddress::parse(input, to_options(options)).map(|inner| PyEmailAddress { inner })
+    }
+
+    /// Validates if the given `input` string is an email address or not.
+    #[staticmethod]
+    #[pyo3(signature = (input, options = None))]
+    fn is_valid(input: &str, options: Option<PyRef<'_, PyParsingOptions>>) -> bool {
+        EmailAddress::is_valid(input, to_options(options))
+    }
+
+    /// Validates every item of `inputs`, and returns a list of booleans in the same order.
+    ///
+    /// Items that are not strings (e.g. `None` or `NaN` in a pandas column) are reported as invalid.
+    /// The GIL is released while validating.
+    #[staticmethod]
+    #[pyo3(signature = (inputs, options = None))]
+    fn validate_many(
+        py: Python<'_>,
+        inputs: &Bound<'_, PyAny>,
+        options: Option<PyRef<'_, PyParsingOptions>>,
+    ) -> PyResult<Vec<bool>> {
+        let options = to_options(options);
+        let inputs: Vec<Option<String>> = inputs
+            .try_iter()?
+            .map(|item| item.map(|item| item.extract::<String>().ok()))
+            .collect::<PyResult<_>>()?;
+        // Non-string items are validated as the empty string, which is never a valid address.
+        Ok(py.detach(|| {
+            EmailAddress::validate_many(inputs.iter().map(|input| input.as_deref().unwrap_or("")), options)
+                .map(|result| result.is_valid)
+                .collect()
+        }))
+    }
+
+    /// The local part of the email address.
+    #[getter]
+    fn local_part(&self) -> &str {
+        self.inner.get_local_part()
+    }
+
+    /// The domain of the email address.
+    #[getter]
+    fn domain(&self) -> &str {
+        self.inner.get_domain()
+    }
+
+    fn __str__(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("EmailAddress({:?})", self.inner.as_str())
+    }
+
+    fn __copy__(&self) -> Self {
+        PyEmailAddress {
+            inner: self.inner.clone(),
+        }
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.__copy__()
+    }
+}
+
+/// An RFC 5322, and RFC 6532 compliant email address parser.
+#[pymodule]
+fn email_address_parser(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEmailAddress>()?;
+    module.add_class::<PyParsingOptions>()?;
+    Ok(())
+}
@@ -0,0 +1,367 @@
+//! Regular expressions equivalent to the grammar implemented in `nom_parser`, for the places where
+//! only a regex can be used; e.g. HTML `pattern` attributes or the regex-based npm package.
+//!
+//! The expressions only use syntax that ECMAScript (with or without the `u` or `v` flag), PCRE, and
+//! the `regex` crate agree on: non-capturing groups, `\xHH` escapes, and character classes, which
+//! are negated where they include non-ASCII characters so that they need no Unicode escapes.
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const WSP: &str = r"[\x09\x20]";
+const ATEXT: &str = r"[^\x00-\x20\x22\x28\x29\x2C\x2E\x3A-\x3C\x3E\x40\x5B-\x5D\x7F]";
+const ATEXT_NO_DASH: &str = r"[^\x00-\x20\x22\x28\x29\x2C\x2D\x2E\x3A-\x3C\x3E\x40\x5B-\x5D\x7F]";
+const QTEXT: &str = r"[^\x00\x09\x0A\x0D\x20\x22\x5C]";
+const DTEXT: &str = r"[^\x00\x09\x0A\x0D\x20\x5B-\x5D]";
+const CTEXT: &str = r"[^\x00\x09\x0A\x0D\x20\x28\x29\x5C]";
+const QUOTED_PAIR: &str = r"\x5C[\x00-\x7F]";
+
+/// The regular expression flavours [`RegexSource`] can produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RegexDialect {
+    /// JavaScript `RegExp`, and HTML `pattern` attributes; preferably used with the `u` flag.
+    EcmaScript,
+    /// PCRE2 (e.g. PHP `preg_*` functions); preferably used with the `u` modifier.
+    ///
+    /// Productions are declared once in a `(?(DEFINE)...)` group, and called as subroutines,
+    /// which keeps the expression short and allows comments to nest without bound.
+    Pcre,
+    /// The `regex` crate.
+    Rust,
+}
+
+/// Builds regular expression sources equivalent to the parser, from the same grammar.
+///
+/// The sources are not anchored; wrap them in `^(?:...)$` to match whole inputs
+/// (HTML `pattern` attributes are anchored implicitly). With PCRE, use `\A(?:...)\z` or the `D`
+/// modifier instead, as `$` also matches before a final line feed. The sources are meant for
+/// validation only, and hence contain no capturing groups.
+///
+/// Regular expressions cannot express everything the parser does, so the sources differ from it
+/// in the following ways:
+/// - Nested comments are only matched up to `max_comment_depth` levels, except with [`RegexDialect::Pcre`].
+/// - The parser limits obsolete domains to 128 labels when their syntax is only valid with lax parsing,
+///   whereas the sources don't.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let source = RegexSource::new(RegexDialect::EcmaScript, false).address();
+/// let html = format!("<input type=\"text\" name=\"email\" pattern=\"{}\">", source);
+/// assert!(!source.contains(['"', '&', '<']), "no HTML escaping needed");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegexSource {
+    /// The flavour of the produced expressions.
+    pub dialect: RegexDialect,
+    /// Also matches obsolete parts as defined by RFC 5322, like `ParsingOptions::new(true)`.
+    pub is_lax: bool,
+    /// How deep comments can be nested, with dialects that need a bound; at least 1.
+    pub max_comment_depth: usize,
+}
+
+impl RegexSource {
+    /// Instantiates a builder of `dialect` expressions, matching what the parser accepts with
+    /// `ParsingOptions::new(is_lax)`; comments can be nested 3 levels deep.
+    pub fn new(dialect: RegexDialect, is_lax: bool) -> RegexSource {
+        RegexSource {
+            dialect,
+            is_lax,
+            max_comment_depth: 3,
+        }
+    }
+
+    /// Returns the source of an expression matching email addresses.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let source = RegexSource::new(RegexDialect::Rust, false).address();
+    /// let regex = regex::Regex::new(&format!("^(?:{})$", source)).unwrap();
+    /// assert!(regex.is_match("foo@bar.com"));
+    /// assert!(!regex.is_match("foo@-bar.com"));
+    /// ```
+    pub fn address(&self) -> String {
+        self.build(|grammar| {
+            let strict = format!("{}@{}", grammar.local_part_strict(), grammar.domain_strict());
+            if !grammar.is_lax {
+                return strict;
+            }
+            let obs = format!("{}@{}", grammar.local_part_obs(), grammar.domain_obs());
+            format!("(?:{}|{})", strict, obs)
+        })
+    }
+
+    /// Returns the source of an expression matching the local part of an address.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let source = RegexSource::new(RegexDialect::Rust, true).local_part();
+    /// let regex = regex::Regex::new(&format!("^(?:{})$", source)).unwrap();
+    /// assert!(regex.is_match("foo. bar"));
+    /// assert!(!regex.is_match("foo@bar"));
+    /// ```
+    pub fn local_part(&self) -> String {
+        self.build(|grammar| {
+            if grammar.is_lax {
+                grammar.local_part_obs()
+            } else {
+                grammar.local_part_strict()
+            }
+        })
+    }
+
+    /// Returns the source of an expression matching the domain of an address.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let source = RegexSource::new(RegexDialect::Pcre, false).domain();
+    /// assert!(source.starts_with("(?(DEFINE)"));
+    /// ```
+    pub fn domain(&self) -> String {
+        self.build(|grammar| {
+            if grammar.is_lax {
+                grammar.domain_obs()
+            } else {
+                grammar.domain_strict()
+            }
+        })
+    }
+
+    fn build(&self, production: impl FnOnce(&mut Grammar) -> String) -> String {
+        let mut grammar = Grammar {
+            dialect: self.dialect,
+            is_lax: self.is_lax,
+            max_comment_depth: self.max_comment_depth.max(1),
+            definitions: Vec::new(),
+        };
+        let source = production(&mut grammar);
+        if grammar.definitions.is_empty() {
+            return source;
+        }
+        let definitions: String = grammar
+            .definitions
+            .iter()
+            .map(|(name, body)| format!("(?<{}>{})", name, body))
+            .collect();
+        format!("(?(DEFINE){}){}", definitions, source)
+    }
+}
+
+/// Writes the productions of the grammar as regular expressions; the comments name the
+/// corresponding `nom_parser` functions, whose documentation has the grammar.
+struct Grammar {
+    dialect: RegexDialect,
+    is_lax: bool,
+    max_comment_depth: usize,
+    definitions: Vec<(&'static str, String)>,
+}
+
+impl Grammar {
+    /// Either inlines `body` as a group, or, with PCRE, defines it once and calls it by `name`.
+    fn rule(&mut self, name: &'static str, body: impl FnOnce(&mut Grammar) -> String) -> String {
+        if self.dialect != RegexDialect::Pcre {
+            let body = body(self);
+            return format!("(?:{})", body);
+        }
+        if !self.definitions.iter().any(|(defined, _)| *defined == name) {
+            let body = body(self);
+            self.definitions.push((name, body));
+        }
+        format!("(?&{})", name)
+    }
+
+    // `local_part_strict`
+    fn local_part_strict(&mut self) -> String {
+        self.rule("local_part", |g| format!("{}|{}", g.dot_atom(), g.quoted_string()))
+    }
+
+    // `domain_strict`
+    fn domain_strict(&mut self) -> String {
+        self.rule("domain", |g| format!("{}|{}", g.dot_atom(), g.domain_literal()))
+    }
+
+    // `local_part_obs`
+    fn local_part_obs(&mut self) -> String {
+        self.rule("local_part_obs", |g| {
+            format!("{}|{}|{}", g.obs_local_part(), g.dot_atom(), g.quoted_string())
+        })
+    }
+
+    // `domain_obs`
+    fn domain_obs(&mut self) -> String {
+        self.rule("domain_obs", |g| {
+            format!("{}|{}|{}", g.obs_domain(), g.dot_atom(), g.domain_literal())
+        })
+    }
+
+    // `dot_atom_inner` and `dot_atom_text`; `CFWS*` is written `CFWS?`, which matches the same
+    // inputs (FWS followed by FWS is FWS), without exponential backtracking.
+    fn dot_atom(&mut self) -> String {
+        self.rule("dot_atom", |g| {
+            let label = g.dot_atom_label();
+            format!("{wsp}?{label}(?:\\.{cfws}?{label})*{wsp}?", wsp = WSP, label = label, cfws = g.cfws())
+        })
+    }
+
+    // `dot_atom_label`
+    fn dot_atom_label(&mut self) -> String {
+        self.rule("dot_atom_label", |_| {
+            format!("{no_dash}(?:{atext}*{no_dash})?", no_dash = ATEXT_NO_DASH, atext = ATEXT)
+        })
+    }
+
+    // `obs_local_part_inner`; every `word` starts and ends with an optional CFWS already, which
+    // makes the surrounding `FWS*` and `CFWS*` redundant.
+    fn obs_local_part(&mut self) -> String {
+        self.rule("obs_local_part", |g| {
+            let word = g.word();
+            format!("{word}(?:\\.{word})*", word = word)
+        })
+    }
+
+    // `word` and `atom`
+    fn word(&mut self) -> String {
+        self.rule("word", |g| {
+            let cfws = g.cfws();
+            format!("{cfws}?{atext}+{cfws}?|{}", g.quoted_string(), cfws = cfws, atext = ATEXT)
+        })
+    }
+
+    // `obs_domain_inner`, flattened: once a separator is seen, the recursive `obs_domain_plus`
+    // also allows labels to be separated by CFWS alone.
+    fn obs_domain(&mut self) -> String {
+        self.rule("obs_domain", |g| {
+            let fws = g.fws();
+            let cfws = g.cfws();
+            format!(
+                "{cfws}?{label}(?:{cfws}?{sep}{cfws}?{label}(?:(?:{cfws}?{sep}{cfws}?|{cfws}){label})*)?{fws}?",
+                cfws = cfws,
+                fws = fws,
+                label = format!("{}+", ATEXT_NO_DASH),
+                sep = r"(?:\.|-+)",
+            )
+        })
+    }
+
+    // `quoted_string_inner`
+    fn quoted_string(&mut self) -> String {
+        self.rule("quoted_string", |g| {
+            let fws = g.fws();
+            let cfws = g.cfws();
+            format!(
+                "{cfws}?\\x22(?:{fws}?(?:{qtext}|{qp}))*{fws}?\\x22{cfws}?",
+                cfws = cfws,
+                fws = fws,
+                qtext = QTEXT,
+                qp = QUOTED_PAIR,
+            )
+        })
+    }
+
+    // `domain_literal_inner`
+    fn domain_literal(&mut self) -> String {
+        self.rule("domain_literal", |g| {
+            let fws = g.fws();
+            let cfws = g.cfws();
+            format!(
+                "{cfws}?\\[(?:{fws}?{dtext})*{fws}?\\]{cfws}?",
+                cfws = cfws,
+                fws = fws,
+                dtext = DTEXT,
+            )
+        })
+    }
+
+    // `cfws`
+    fn cfws(&mut self) -> String {
+        self.rule("cfws", |g| {
+            let fws = g.fws();
+            let comment = g.comment();
+            format!("(?:{fws}?{comment})+{fws}?|{fws}", fws = fws, comment = comment)
+        })
+    }
+
+    // `comment`; PCRE recurses, the other dialects nest up to `max_comment_depth` levels.
+    fn comment(&mut self) -> String {
+        if self.dialect == RegexDialect::Pcre {
+            return self.rule("comment", |g| {
+                format!(
+                    "\\((?:{fws}?(?:{ctext}|{qp}|(?&comment)))*{fws}?\\)",
+                    fws = g.fws(),
+                    ctext = CTEXT,
+                    qp = QUOTED_PAIR,
+                )
+            });
+        }
+        let fws = self.fws();
+        let mut comment = String::new();
+        for _ in 0..self.max_comment_depth {
+            let nested = if comment.is_empty() { String::new() } else { format!("|{}", comment) };
+            comment = format!(
+                "\\((?:{fws}?(?:{ctext}|{qp}{nested}))*{fws}?\\)",
+                fws = fws,
+                ctext = CTEXT,
+                qp = QUOTED_PAIR,
+                nested = nested,
+            );
+        }
+        comment
+    }
+
+    // `fws`
+    fn fws(&mut self) -> String {
+        self.rule("fws", |_| {
+            format!("(?:{wsp}+|{wsp}*\\r\\n{wsp}+)(?:\\r\\n{wsp}+)*", wsp = WSP)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inlines_productions_except_with_pcre() {
+        let source = RegexSource::new(RegexDialect::EcmaScript, false).domain();
+        assert!(!source.contains("(?&"));
+        assert!(!source.contains("(?<"));
+
+        let source = RegexSource::new(RegexDialect::Pcre, false).domain();
+        assert!(source.starts_with("(?(DEFINE)"));
+        assert!(source.ends_with("(?&domain)"));
+        assert_eq!(source.matches("(?<cfws>").count(), 1);
+        assert!(source.contains("(?&comment)"));
+    }
+
+    #[test]
+    fn bounds_comment_nesting() {
+        let comment_openings = |max_comment_depth| {
+            let regex_source = RegexSource {
+                max_comment_depth,
+                ..RegexSource::new(RegexDialect::Rust, false)
+            };
+            regex_source.domain().matches(r"\((?:").count()
+        };
+        assert_eq!(comment_openings(0), comment_openings(1));
+        assert!(comment_openings(2) > comment_openings(1));
+    }
+
+    #[test]
+    fn uses_printable_ascii_only() {
+        for dialect in [RegexDialect::EcmaScript, RegexDialect::Pcre, RegexDialect::Rust] {
+            for is_lax in [false, true] {
+                let regex_source = RegexSource::new(dialect, is_lax);
+                for source in [regex_source.address(), regex_source.local_part(), regex_source.domain()] {
+                    assert!(source.bytes().all(|b| (0x21..0x7f).contains(&b)), "{}", source);
+                }
+            }
+        }
+    }
+}
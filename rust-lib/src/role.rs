@@ -0,0 +1,216 @@
+//! Detection of role accounts and system mailboxes, such as `postmaster@` or `noreply@`.
+use crate::cfws::{tokens, unquote, Token};
+use crate::email_address::EmailAddress;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Mailbox names of roles rather than people, by category.
+const ROLE_NAMES: &[(&str, RoleKind)] = &[
+    ("info", RoleKind::Business),
+    ("marketing", RoleKind::Business),
+    ("sales", RoleKind::Business),
+    ("support", RoleKind::Business),
+    ("abuse", RoleKind::NetworkOperations),
+    ("noc", RoleKind::NetworkOperations),
+    ("security", RoleKind::NetworkOperations),
+    ("postmaster", RoleKind::Service),
+    ("hostmaster", RoleKind::Service),
+    ("usenet", RoleKind::Service),
+    ("news", RoleKind::Service),
+    ("webmaster", RoleKind::Service),
+    ("www", RoleKind::Service),
+    ("uucp", RoleKind::Service),
+    ("ftp", RoleKind::Service),
+    ("admin", RoleKind::Administrative),
+    ("administrator", RoleKind::Administrative),
+    ("root", RoleKind::Administrative),
+    ("sysadmin", RoleKind::Administrative),
+    ("mailer-daemon", RoleKind::System),
+    ("daemon", RoleKind::System),
+    ("nobody", RoleKind::System),
+    ("bounce", RoleKind::System),
+    ("bounces", RoleKind::System),
+    ("noreply", RoleKind::NoReply),
+    ("no-reply", RoleKind::NoReply),
+    ("no_reply", RoleKind::NoReply),
+    ("donotreply", RoleKind::NoReply),
+    ("do-not-reply", RoleKind::NoReply),
+    ("do_not_reply", RoleKind::NoReply),
+];
+
+/// The category of a role account, returned by [`RoleClassifier::classify`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoleKind {
+    /// A business mailbox of [RFC 2142](https://tools.ietf.org/html/rfc2142) section 3; e.g. `info` or `sales`.
+    Business,
+    /// A network operations mailbox of RFC 2142 section 4; e.g. `abuse` or `security`.
+    NetworkOperations,
+    /// A mailbox of a specific service of RFC 2142 section 5; e.g. `postmaster` or `webmaster`.
+    Service,
+    /// An administrator's mailbox; e.g. `admin` or `root`.
+    Administrative,
+    /// A mailbox of the mail system itself; e.g. `mailer-daemon` or `bounces`.
+    System,
+    /// A sender that does not read replies; e.g. `noreply` or `do-not-reply`.
+    NoReply,
+}
+
+/// Classifies the local parts of role accounts, such as `postmaster` or `noreply`.
+///
+/// The local part is compared to `names` after unquoting it, dropping comments and white space,
+/// case-folding it, and stripping a subaddress, so `"Abuse"`, `abuse+spam`, and `ABUSE` are all
+/// classified as `abuse`.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let email = EmailAddress::parse("Postmaster+bounces@example.com", None).unwrap();
+/// assert_eq!(RoleClassifier::new().classify(&email), Some(RoleKind::Service));
+///
+/// let mut classifier = RoleClassifier::new();
+/// classifier.names.push((String::from("careers"), RoleKind::Business));
+/// let email = EmailAddress::parse("careers@example.com", None).unwrap();
+/// assert_eq!(classifier.classify(&email), Some(RoleKind::Business));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RoleClassifier {
+    /// The known mailbox names and their categories; defaults to the names of RFC 2142, and common
+    /// administrator, mail system, and no-reply names.
+    pub names: Vec<(String, RoleKind)>,
+    /// The character that starts a subaddress, e.g. `+` in `abuse+spam`, or `None` to keep it.
+    pub subaddress_separator: Option<char>,
+}
+
+impl RoleClassifier {
+    /// Instantiates a classifier with the bundled list of names, stripping subaddresses after a `+`.
+    pub fn new() -> RoleClassifier {
+        RoleClassifier {
+            names: ROLE_NAMES.iter().map(|&(name, kind)| (String::from(name), kind)).collect(),
+            subaddress_separator: Some('+'),
+        }
+    }
+
+    /// Returns the category of the local part of `email`, or `None` if it is not a known role.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let classifier = RoleClassifier::new();
+    /// let email = EmailAddress::parse("\"No-Reply\"@example.com", None).unwrap();
+    /// assert_eq!(classifier.classify(&email), Some(RoleKind::NoReply));
+    ///
+    /// let email = EmailAddress::parse("jane@example.com", None).unwrap();
+    /// assert_eq!(classifier.classify(&email), None);
+    /// ```
+    pub fn classify(&self, email: &EmailAddress) -> Option<RoleKind> {
+        let mut local_part = normalize(email.get_local_part());
+        if let Some(start) = self.subaddress_separator.and_then(|separator| local_part.find(separator)) {
+            local_part.truncate(start);
+        }
+        self.names
+            .iter()
+            .find(|(name, _)| name.to_lowercase() == local_part)
+            .map(|&(_, kind)| kind)
+    }
+}
+
+impl Default for RoleClassifier {
+    fn default() -> Self {
+        RoleClassifier::new()
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Returns the category of the local part if it names a role, such as `postmaster` or
+    /// `noreply`, rather than a person; use [`RoleClassifier`] for other names.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("abuse@example.com", None).unwrap();
+    /// assert_eq!(email.role_kind(), Some(RoleKind::NetworkOperations));
+    ///
+    /// let email = EmailAddress::parse("jane@example.com", None).unwrap();
+    /// assert_eq!(email.role_kind(), None);
+    /// ```
+    pub fn role_kind(&self) -> Option<RoleKind> {
+        RoleClassifier::new().classify(self)
+    }
+}
+
+/// Unquotes `local_part`, drops its comments and white space, and case-folds it.
+fn normalize(local_part: &str) -> String {
+    let mut text = String::with_capacity(local_part.len());
+    for (token, range) in tokens(local_part) {
+        match token {
+            Token::QuotedString => text.push_str(&unquote(&local_part[range.start + 1..]).0),
+            Token::Comment | Token::WhiteSpace => {}
+            _ => text.push_str(&local_part[range]),
+        }
+    }
+    text.to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsingOptions;
+
+    fn role_kind(address: &str) -> Option<RoleKind> {
+        EmailAddress::parse(address, Some(ParsingOptions::new(true))).unwrap().role_kind()
+    }
+
+    #[test]
+    fn classifies_rfc_2142_mailboxes() {
+        let cases = [
+            ("info@example.com", RoleKind::Business),
+            ("SUPPORT@example.com", RoleKind::Business),
+            ("abuse@example.com", RoleKind::NetworkOperations),
+            ("noc@example.com", RoleKind::NetworkOperations),
+            ("postmaster@example.com", RoleKind::Service),
+            ("hostmaster@example.com", RoleKind::Service),
+            ("admin@example.com", RoleKind::Administrative),
+            ("MAILER-DAEMON@example.com", RoleKind::System),
+            ("no-reply@example.com", RoleKind::NoReply),
+        ];
+        for (address, expected) in cases {
+            assert_eq!(role_kind(address), Some(expected), "{:?}", address);
+        }
+    }
+
+    #[test]
+    fn normalizes_local_parts() {
+        assert_eq!(normalize("\"Post\\master\""), "postmaster");
+        assert_eq!(normalize("(comment) ABUSE (more)"), "abuse");
+        assert_eq!(normalize("no\"-\"reply"), "no-reply");
+        assert_eq!(role_kind("\"abuse\"@example.com"), Some(RoleKind::NetworkOperations));
+        assert_eq!(role_kind("abuse+spam@example.com"), Some(RoleKind::NetworkOperations));
+        assert_eq!(role_kind(" (x) root @example.com"), Some(RoleKind::Administrative));
+    }
+
+    #[test]
+    fn keeps_people_and_other_names() {
+        for address in ["jane@example.com", "information@example.com", "+abuse@example.com", "\"no reply\"@example.com"] {
+            assert_eq!(role_kind(address), None, "{:?}", address);
+        }
+        let classifier = RoleClassifier {
+            subaddress_separator: None,
+            ..RoleClassifier::new()
+        };
+        let email = EmailAddress::parse("abuse+spam@example.com", None).unwrap();
+        assert_eq!(classifier.classify(&email), None);
+        let classifier = RoleClassifier {
+            names: vec![(String::from("Careers"), RoleKind::Business)],
+            subaddress_separator: Some('-'),
+        };
+        let email = EmailAddress::parse("careers-2024@example.com", None).unwrap();
+        assert_eq!(classifier.classify(&email), Some(RoleKind::Business));
+        assert_eq!(classifier.classify(&EmailAddress::parse("info@example.com", None).unwrap()), None);
+    }
+}
@@ -0,0 +1,597 @@
+//! Verification that a mail server accepts a recipient, without sending mail.
+//!
+//! [`SmtpVerifier::verify`] speaks the minimal SMTP dialogue of
+//! [RFC 5321](https://tools.ietf.org/html/rfc5321) (`EHLO`, `MAIL FROM:<>`, `RCPT TO`, `RSET`,
+//! `QUIT`) over any `Read + Write` transport, e.g. a `TcpStream` connected to port 25 of an MX
+//! host found with `dns::check_deliverability`, and classifies the reply to `RCPT TO`.
+//! [`FakeServer`] replies from a script, e.g. in tests.
+//!
+//! # Examples
+//! ```
+//! use email_address_parser::smtp_verify::*;
+//! use email_address_parser::EmailAddress;
+//!
+//! let mut server = FakeServer::new("220 mx.example.com ESMTP");
+//! server.expect("EHLO", "250 mx.example.com");
+//! server.expect("MAIL FROM:<>", "250 OK");
+//! server.expect("RCPT TO:<jane@example.com>", "550 5.1.1 No such user");
+//! server.expect("RSET", "250 OK");
+//! server.expect("QUIT", "221 Bye");
+//!
+//! let email = EmailAddress::parse("jane@example.com", None).unwrap();
+//! let verification = SmtpVerifier::new().verify(&mut server, &email).unwrap();
+//! assert_eq!(verification.status, RecipientStatus::Rejected);
+//! ```
+use crate::cfws::strip_cfws;
+use crate::email_address::EmailAddress;
+use std::collections::hash_map::RandomState;
+use std::collections::VecDeque;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// The longest reply line that is read, in octets; RFC 5321 allows 512.
+const MAX_LINE_LENGTH: u64 = 4096;
+/// The most lines of a reply that are read.
+const MAX_REPLY_LINES: usize = 256;
+
+/// A reply of an SMTP server.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SmtpReply {
+    /// The three-digit reply code, e.g. 250.
+    pub code: u16,
+    /// The text of every line of the reply, after the code.
+    pub lines: Vec<String>,
+}
+
+impl SmtpReply {
+    /// Returns `true` for a positive completion reply (2yz).
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::smtp_verify::SmtpReply;
+    ///
+    /// assert!(SmtpReply { code: 250, lines: vec![String::from("OK")] }.is_positive());
+    /// ```
+    pub fn is_positive(&self) -> bool {
+        (200..300).contains(&self.code)
+    }
+}
+
+impl fmt::Display for SmtpReply {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(formatter, "{} {}", self.code, self.lines.join(" "))
+    }
+}
+
+/// The reason a verification could not be completed.
+#[derive(Debug)]
+pub enum SmtpError {
+    /// Reading from or writing to the transport failed, or the server closed the connection.
+    Io(io::Error),
+    /// The server sent something that is not an SMTP reply.
+    MalformedReply(String),
+    /// The server refused the greeting, `EHLO`, or `MAIL FROM` with this reply, or replied to
+    /// `RCPT TO` with an intermediate reply (3yz), which RFC 5321 does not allow for it.
+    Refused(SmtpReply),
+    /// The address has Unicode characters, but the server does not support `SMTPUTF8`.
+    Utf8NotSupported,
+    /// The address has a line break that is not part of a comment or of white space between its
+    /// atoms, e.g. in a quoted string, or `client_name` has one, so a command would be split in
+    /// two; nothing was sent.
+    LineBreakInCommand,
+}
+
+impl fmt::Display for SmtpError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            SmtpError::Io(error) => write!(formatter, "{}", error),
+            SmtpError::MalformedReply(line) => write!(formatter, "malformed reply: {:?}", line),
+            SmtpError::Refused(reply) => write!(formatter, "refused: {}", reply),
+            SmtpError::Utf8NotSupported => write!(formatter, "the server does not support SMTPUTF8"),
+            SmtpError::LineBreakInCommand => write!(formatter, "a command would have a line break"),
+        }
+    }
+}
+
+impl std::error::Error for SmtpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SmtpError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SmtpError {
+    fn from(error: io::Error) -> Self {
+        SmtpError::Io(error)
+    }
+}
+
+/// How a server replied to `RCPT TO`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RecipientStatus {
+    /// The server accepted the recipient, and rejected a random one at the same domain.
+    Accepted,
+    /// The server accepted the recipient, but also a random one at the same domain, so it likely
+    /// accepts every address.
+    CatchAll,
+    /// The server rejected the recipient permanently (5yz).
+    Rejected,
+    /// The server deferred the recipient with 450 or 451, as greylisting servers do; trying again
+    /// after some minutes may succeed.
+    Greylisted,
+    /// The server deferred the recipient with another 4yz reply, e.g. when it is busy.
+    TemporaryFailure,
+}
+
+impl RecipientStatus {
+    /// Classifies the reply code to `RCPT TO`, before any catch-all check; `None` for codes that
+    /// are not a completion reply (2yz, 4yz, or 5yz), such as the intermediate 3yz ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::smtp_verify::RecipientStatus;
+    ///
+    /// assert_eq!(RecipientStatus::from_code(451), Some(RecipientStatus::Greylisted));
+    /// assert_eq!(RecipientStatus::from_code(550), Some(RecipientStatus::Rejected));
+    /// assert_eq!(RecipientStatus::from_code(354), None);
+    /// ```
+    pub fn from_code(code: u16) -> Option<RecipientStatus> {
+        match code {
+            200..=299 => Some(RecipientStatus::Accepted),
+            450 | 451 => Some(RecipientStatus::Greylisted),
+            400..=499 => Some(RecipientStatus::TemporaryFailure),
+            500..=599 => Some(RecipientStatus::Rejected),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of [`SmtpVerifier::verify`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Verification {
+    /// How the server replied to the recipient.
+    pub status: RecipientStatus,
+    /// The reply of the server to `RCPT TO` for the recipient.
+    pub reply: SmtpReply,
+}
+
+/// Asks a mail server whether it accepts a recipient, then aborts the transaction.
+///
+/// The dialogue uses the null reverse-path, `MAIL FROM:<>`, and requests `SMTPUTF8` for
+/// addresses with Unicode characters. When the recipient is accepted, a random local part at
+/// the same domain is tried too, to tell [`RecipientStatus::CatchAll`] servers apart.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::smtp_verify::*;
+/// use email_address_parser::EmailAddress;
+///
+/// let mut server = FakeServer::new("220 mx.example.com ESMTP");
+/// server.expect("EHLO verifier.example.org", "250 mx.example.com");
+/// server.expect("MAIL FROM:<>", "250 OK");
+/// server.expect("RCPT TO:<jane@example.com>", "250 OK");
+/// server.expect("RSET", "250 OK");
+/// server.expect("QUIT", "221 Bye");
+///
+/// let verifier = SmtpVerifier { client_name: String::from("verifier.example.org"), detect_catch_all: false };
+/// let email = EmailAddress::parse("jane@example.com", None).unwrap();
+/// assert_eq!(verifier.verify(&mut server, &email).unwrap().status, RecipientStatus::Accepted);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SmtpVerifier {
+    /// The domain name of the client, sent with `EHLO`; defaults to `localhost`.
+    pub client_name: String,
+    /// Whether a random recipient is tried after an accepted one, to detect catch-all servers.
+    pub detect_catch_all: bool,
+}
+
+impl SmtpVerifier {
+    /// Instantiates a verifier detecting catch-all servers, introducing itself as `localhost`.
+    pub fn new() -> SmtpVerifier {
+        SmtpVerifier {
+            client_name: String::from("localhost"),
+            detect_catch_all: true,
+        }
+    }
+
+    /// Runs the dialogue over `transport`, which must be connected to the server and not yet
+    /// greeted, to find out whether the server accepts `email`.
+    ///
+    /// Comments and white space, which lax parsing allows but SMTP does not, are dropped from
+    /// `email` before it is sent.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use email_address_parser::smtp_verify::*;
+    /// use email_address_parser::EmailAddress;
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("mx.example.com:25").unwrap();
+    /// let email = EmailAddress::parse("jane@example.com", None).unwrap();
+    /// let verification = SmtpVerifier::new().verify(stream, &email).unwrap();
+    /// println!("{:?}", verification.status);
+    /// ```
+    pub fn verify(&self, transport: impl Read + Write, email: &EmailAddress) -> Result<Verification, SmtpError> {
+        let (local_part, domain) = (strip_cfws(email.get_local_part()), strip_cfws(email.get_domain()));
+        if [&local_part, &domain, &self.client_name].iter().any(|part| part.contains(['\r', '\n'])) {
+            return Err(SmtpError::LineBreakInCommand);
+        }
+        let mut connection = Connection {
+            reader: BufReader::new(transport),
+        };
+        expect_positive(connection.read_reply()?)?;
+        let mut reply = connection.command(&format!("EHLO {}", self.client_name))?;
+        let supports_utf8 = reply.is_positive()
+            && reply.lines[1..]
+                .iter()
+                .any(|line| line.split_whitespace().next().is_some_and(|keyword| keyword.eq_ignore_ascii_case("SMTPUTF8")));
+        if !reply.is_positive() {
+            reply = connection.command(&format!("HELO {}", self.client_name))?;
+        }
+        expect_positive(reply)?;
+
+        let is_utf8 = !(local_part.is_ascii() && domain.is_ascii());
+        if is_utf8 && !supports_utf8 {
+            connection.quit();
+            return Err(SmtpError::Utf8NotSupported);
+        }
+        expect_positive(connection.command(if is_utf8 { "MAIL FROM:<> SMTPUTF8" } else { "MAIL FROM:<>" })?)?;
+        let reply = connection.command(&format!("RCPT TO:<{}@{}>", local_part, domain))?;
+        let Some(mut status) = RecipientStatus::from_code(reply.code) else {
+            connection.quit();
+            return Err(SmtpError::Refused(reply));
+        };
+        if status == RecipientStatus::Accepted && self.detect_catch_all {
+            let probe = format!("RCPT TO:<{}@{}>", random_local_part(), domain);
+            if connection.command(&probe)?.is_positive() {
+                status = RecipientStatus::CatchAll;
+            }
+        }
+        connection.command("RSET")?;
+        connection.quit();
+        Ok(Verification { status, reply })
+    }
+}
+
+impl Default for SmtpVerifier {
+    fn default() -> Self {
+        SmtpVerifier::new()
+    }
+}
+
+struct Connection<T> {
+    reader: BufReader<T>,
+}
+
+impl<T: Read + Write> Connection<T> {
+    /// Sends `command`, and returns the reply to it.
+    fn command(&mut self, command: &str) -> Result<SmtpReply, SmtpError> {
+        let transport = self.reader.get_mut();
+        transport.write_all(command.as_bytes())?;
+        transport.write_all(b"\r\n")?;
+        transport.flush()?;
+        self.read_reply()
+    }
+
+    /// Sends `QUIT`; the server is leaving anyway, so its reply, or failure to reply, is ignored.
+    fn quit(&mut self) {
+        let _ = self.command("QUIT");
+    }
+
+    /// Reads a possibly multiline reply, whose lines but the last have a `-` after the code.
+    fn read_reply(&mut self) -> Result<SmtpReply, SmtpError> {
+        let mut lines = Vec::new();
+        loop {
+            let mut line = Vec::new();
+            (&mut self.reader).take(MAX_LINE_LENGTH).read_until(b'\n', &mut line)?;
+            if line.is_empty() {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\r', '\n']);
+            let code = line
+                .get(..3)
+                .filter(|code| code.bytes().all(|byte| byte.is_ascii_digit()))
+                .and_then(|code| code.parse::<u16>().ok())
+                .filter(|code| (200..600).contains(code));
+            let (code, is_last) = match (code, line.as_bytes().get(3)) {
+                (Some(code), None | Some(b' ')) => (code, true),
+                (Some(code), Some(b'-')) => (code, false),
+                _ => return Err(SmtpError::MalformedReply(String::from(line))),
+            };
+            lines.push((code, String::from(line.get(4..).unwrap_or(""))));
+            if lines[0].0 != code || lines.len() > MAX_REPLY_LINES {
+                return Err(SmtpError::MalformedReply(String::from(line)));
+            }
+            if is_last {
+                return Ok(SmtpReply {
+                    code,
+                    lines: lines.into_iter().map(|(_, text)| text).collect(),
+                });
+            }
+        }
+    }
+}
+
+fn expect_positive(reply: SmtpReply) -> Result<SmtpReply, SmtpError> {
+    if reply.is_positive() {
+        Ok(reply)
+    } else {
+        Err(SmtpError::Refused(reply))
+    }
+}
+
+/// Returns a local part that is unlikely to exist.
+fn random_local_part() -> String {
+    format!("verify-{:016x}", RandomState::new().hash_one(()))
+}
+
+/// An in-process SMTP server that replies from a script, implementing `Read + Write` so that it
+/// can be passed to [`SmtpVerifier::verify`] in place of a connection.
+///
+/// Every command sent to it is compared to the next step of the script; if the command starts
+/// with the expected text, ignoring case, the scripted reply is sent, otherwise `503 5.5.1`.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::smtp_verify::*;
+/// use email_address_parser::EmailAddress;
+///
+/// let mut server = FakeServer::new("220 mx.example.com ESMTP");
+/// server.expect("EHLO", "250-mx.example.com\n250 SMTPUTF8");
+/// server.expect("MAIL FROM:<> SMTPUTF8", "250 OK");
+/// server.expect("RCPT TO:<用户@例子.广告>", "250 OK");
+/// server.expect("RCPT TO:", "250 OK");
+/// server.expect("RSET", "250 OK");
+/// server.expect("QUIT", "221 Bye");
+///
+/// let email = EmailAddress::parse("用户@例子.广告", None).unwrap();
+/// let verification = SmtpVerifier::new().verify(&mut server, &email).unwrap();
+/// assert_eq!(verification.status, RecipientStatus::CatchAll);
+/// assert_eq!(server.commands().len(), 6);
+/// assert!(server.is_done());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FakeServer {
+    script: VecDeque<(String, String)>,
+    commands: Vec<String>,
+    received: Vec<u8>,
+    pending: VecDeque<u8>,
+}
+
+impl FakeServer {
+    /// Instantiates a server that greets with `greeting`, and has an empty script.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::smtp_verify::FakeServer;
+    /// use std::io::Read;
+    ///
+    /// let mut greeting = String::new();
+    /// FakeServer::new("220 mx.example.com").read_to_string(&mut greeting).unwrap();
+    /// assert_eq!(greeting, "220 mx.example.com\r\n");
+    /// ```
+    pub fn new(greeting: &str) -> FakeServer {
+        let mut server = FakeServer::default();
+        server.reply(greeting);
+        server
+    }
+
+    /// Adds a step to the script: the next command must start with `command`, and is replied to
+    /// with `reply`, whose lines are separated by `\n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::smtp_verify::FakeServer;
+    /// use std::io::Write;
+    ///
+    /// let mut server = FakeServer::new("220 mx.example.com");
+    /// server.expect("NOOP", "250 OK");
+    /// server.write_all(b"NOOP\r\n").unwrap();
+    /// assert!(server.is_done());
+    /// ```
+    pub fn expect(&mut self, command: &str, reply: &str) {
+        self.script.push_back((String::from(command), String::from(reply)));
+    }
+
+    /// Returns the commands received so far, without line breaks.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::smtp_verify::FakeServer;
+    /// use std::io::Write;
+    ///
+    /// let mut server = FakeServer::new("220 mx.example.com");
+    /// server.write_all(b"NOOP\r\n").unwrap();
+    /// assert_eq!(server.commands(), ["NOOP"]);
+    /// ```
+    pub fn commands(&self) -> &[String] {
+        &self.commands
+    }
+
+    /// Returns `true` if every step of the script has been played.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::smtp_verify::FakeServer;
+    ///
+    /// let mut server = FakeServer::new("220 mx.example.com");
+    /// server.expect("QUIT", "221 Bye");
+    /// assert!(!server.is_done());
+    /// ```
+    pub fn is_done(&self) -> bool {
+        self.script.is_empty()
+    }
+
+    fn reply(&mut self, reply: &str) {
+        for line in reply.lines() {
+            self.pending.extend(line.as_bytes());
+            self.pending.extend(b"\r\n");
+        }
+    }
+}
+
+impl Read for FakeServer {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        self.pending.read(buffer)
+    }
+}
+
+impl Write for FakeServer {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        self.received.extend_from_slice(buffer);
+        while let Some(end) = self.received.windows(2).position(|pair| pair == b"\r\n") {
+            let command = String::from_utf8_lossy(&self.received[..end]).into_owned();
+            self.received.drain(..end + 2);
+            let reply = match self.script.front() {
+                Some((expected, _)) if command.get(..expected.len()).is_some_and(|start| start.eq_ignore_ascii_case(expected)) => {
+                    self.script.pop_front().map(|(_, reply)| reply)
+                }
+                _ => None,
+            };
+            self.reply(reply.as_deref().unwrap_or("503 5.5.1 Unexpected command"));
+            self.commands.push(command);
+        }
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsingOptions;
+
+    fn server(rcpt_replies: &[&str]) -> FakeServer {
+        let mut server = FakeServer::new("220-mx.example.com ESMTP\n220 ready");
+        server.expect("EHLO localhost", "250-mx.example.com\n250-PIPELINING\n250 8BITMIME");
+        server.expect("MAIL FROM:<>", "250 2.1.0 OK");
+        for reply in rcpt_replies {
+            server.expect("RCPT TO:<", reply);
+        }
+        server.expect("RSET", "250 2.0.0 OK");
+        server.expect("QUIT", "221 2.0.0 Bye");
+        server
+    }
+
+    fn verify(server: &mut FakeServer, address: &str) -> Result<Verification, SmtpError> {
+        SmtpVerifier::new().verify(server, &EmailAddress::parse(address, None).unwrap())
+    }
+
+    #[test]
+    fn classifies_recipients() {
+        let cases = [
+            (&["250 2.1.5 OK", "550 5.1.1 No such user"][..], RecipientStatus::Accepted),
+            (&["250 2.1.5 OK", "250 2.1.5 OK"][..], RecipientStatus::CatchAll),
+            (&["550 5.1.1 No such user"][..], RecipientStatus::Rejected),
+            (&["451 4.7.1 Greylisted, try again later"][..], RecipientStatus::Greylisted),
+            (&["452 4.5.3 Too many recipients"][..], RecipientStatus::TemporaryFailure),
+        ];
+        for (replies, expected) in cases {
+            let mut server = server(replies);
+            let verification = verify(&mut server, "jane@example.com").unwrap();
+            assert_eq!(verification.status, expected, "{:?}", replies);
+            assert_eq!(verification.reply.code.to_string(), replies[0][..3]);
+            assert!(server.is_done(), "{:?}", server);
+        }
+    }
+
+    #[test]
+    fn speaks_the_minimal_dialogue() {
+        let mut server = server(&["250 OK", "550 No such user"]);
+        verify(&mut server, "jane@example.com").unwrap();
+        let commands = server.commands();
+        assert_eq!(commands[..3], ["EHLO localhost", "MAIL FROM:<>", "RCPT TO:<jane@example.com>"]);
+        assert!(commands[3].starts_with("RCPT TO:<verify-") && commands[3].ends_with("@example.com>"));
+        assert_eq!(commands[4..], ["RSET", "QUIT"]);
+        assert_ne!(random_local_part(), random_local_part());
+    }
+
+    #[test]
+    fn requires_smtputf8_for_unicode_addresses() {
+        let mut server = server(&[]);
+        let error = verify(&mut server, "jörg@example.com").unwrap_err();
+        assert!(matches!(error, SmtpError::Utf8NotSupported), "{:?}", error);
+        assert_eq!(server.commands(), ["EHLO localhost", "QUIT"]);
+    }
+
+    #[test]
+    fn drops_comments_and_folding_white_space() {
+        let mut server = server(&["250 OK", "550 No such user"]);
+        let email = EmailAddress::parse("(x) jane @(x\r\n y)example.com", Some(ParsingOptions::new(true))).unwrap();
+        SmtpVerifier::new().verify(&mut server, &email).unwrap();
+        let commands = server.commands();
+        assert_eq!(commands[2], "RCPT TO:<jane@example.com>");
+        assert!(commands[3].starts_with("RCPT TO:<verify-") && commands[3].ends_with("@example.com>"));
+        assert_eq!(commands.len(), 6);
+
+        let mut untouched = FakeServer::new("220 mx.example.com");
+        let email = EmailAddress::parse("\"ja\r\n ne\"@example.com", Some(ParsingOptions::new(true))).unwrap();
+        let error = SmtpVerifier::new().verify(&mut untouched, &email).unwrap_err();
+        assert!(matches!(error, SmtpError::LineBreakInCommand), "{:?}", error);
+        assert!(untouched.commands().is_empty());
+    }
+
+    #[test]
+    fn refuses_line_breaks_in_the_client_name() {
+        let mut untouched = FakeServer::new("220 mx.example.com");
+        let verifier = SmtpVerifier {
+            client_name: String::from("localhost\r\nRCPT TO:<x@example.com>"),
+            detect_catch_all: false,
+        };
+        let error = verifier.verify(&mut untouched, &EmailAddress::parse("jane@example.com", None).unwrap()).unwrap_err();
+        assert!(matches!(error, SmtpError::LineBreakInCommand), "{:?}", error);
+        assert!(untouched.commands().is_empty());
+    }
+
+    #[test]
+    fn falls_back_to_helo() {
+        let mut server = FakeServer::new("220 mx.example.com");
+        server.expect("EHLO", "502 5.5.2 Command not recognized");
+        server.expect("HELO localhost", "250 mx.example.com");
+        server.expect("MAIL FROM:<>", "250 OK");
+        server.expect("RCPT TO:<jane@example.com>", "550 No such user");
+        let verification = verify(&mut server, "jane@example.com").unwrap();
+        assert_eq!(verification.status, RecipientStatus::Rejected);
+        assert_eq!(verification.reply.lines, ["No such user"]);
+    }
+
+    #[test]
+    fn reports_refusals_and_broken_servers() {
+        let mut intermediate = server(&["354 Start mail input"]);
+        assert!(matches!(verify(&mut intermediate, "jane@example.com"), Err(SmtpError::Refused(reply)) if reply.code == 354));
+        let mut server = FakeServer::new("554 5.7.1 Go away");
+        assert!(matches!(verify(&mut server, "jane@example.com"), Err(SmtpError::Refused(reply)) if reply.code == 554));
+        let mut server = FakeServer::new("220 mx.example.com");
+        server.expect("EHLO", "250 mx.example.com");
+        server.expect("MAIL FROM:<>", "553 5.1.8 Bad sender");
+        assert!(matches!(verify(&mut server, "jane@example.com"), Err(SmtpError::Refused(reply)) if reply.code == 553));
+        for greeting in ["hello", "220-first\n250 second", "99 too short", ""] {
+            let result = verify(&mut FakeServer::new(greeting), "jane@example.com");
+            match (greeting, result) {
+                ("", Err(SmtpError::Io(error))) => assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof),
+                (_, Err(SmtpError::MalformedReply(_))) => {}
+                (_, other) => panic!("{:?}: {:?}", greeting, other),
+            }
+        }
+    }
+
+    #[test]
+    fn fake_server_rejects_unexpected_commands() {
+        let mut server = FakeServer::new("220 mx.example.com");
+        server.expect("EHLO", "250 mx.example.com");
+        server.write_all(b"HELO x\r\nEH").unwrap();
+        server.write_all(b"LO x\r\n").unwrap();
+        let mut output = String::new();
+        server.read_to_string(&mut output).unwrap();
+        assert_eq!(output, "220 mx.example.com\r\n503 5.5.1 Unexpected command\r\n250 mx.example.com\r\n");
+        assert_eq!(server.commands(), ["HELO x", "EHLO x"]);
+        assert!(server.is_done());
+    }
+}
@@ -0,0 +1,255 @@
+//! Byte spans of the components of a parsed address, e.g. to highlight them in the input.
+use crate::cfws::{tokens, Token};
+use crate::email_address::{EmailAddress, ParsingOptions};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A half-open range `[start, end)` of UTF-8 byte offsets into a string.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::Span;
+///
+/// let span = Span { start: 4, end: 7 };
+/// assert_eq!(&"foo@bar.com"[span.range()], "bar");
+/// assert_eq!(span.len(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Span {
+    /// The offset of the first byte.
+    pub start: usize,
+    /// The offset after the last byte.
+    pub end: usize,
+}
+
+impl Span {
+    /// Returns the span as a range, to index a string with.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::Span;
+    ///
+    /// assert_eq!(Span { start: 1, end: 3 }.range(), 1..3);
+    /// ```
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the length of the span, in bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::Span;
+    ///
+    /// assert_eq!(Span { start: 1, end: 3 }.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the span has no bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::Span;
+    ///
+    /// assert!(Span { start: 3, end: 3 }.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(span: Span) -> Self {
+        span.range()
+    }
+}
+
+/// The spans of the components of an address, returned by [`EmailAddress::parse_with_spans`].
+///
+/// The local part and domain include the comments and white space around them, as
+/// [`EmailAddress::get_local_part`] and [`EmailAddress::get_domain`] do. Every list is in input
+/// order; a comment nested in another is part of the outer one, and is not listed itself.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let input = "\"john q\".public@example.com";
+/// let (_, spans) = EmailAddress::parse_with_spans(input, Some(ParsingOptions::new(true))).unwrap();
+/// assert_eq!(&input[spans.quoted_strings[0].range()], "\"john q\"");
+/// assert_eq!(&input[spans.local_part_labels[0].range()], "public");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AddressSpans {
+    /// The local part, before the `@`.
+    pub local_part: Span,
+    /// The `@` separating the local part and the domain.
+    pub at: Span,
+    /// The domain, after the `@`.
+    pub domain: Span,
+    /// The atoms between the dots of the local part; quoted strings are not labels.
+    pub local_part_labels: Vec<Span>,
+    /// The labels between the dots of the domain; none for a domain literal.
+    pub domain_labels: Vec<Span>,
+    /// The dots separating labels and quoted strings, in the local part and the domain.
+    pub dots: Vec<Span>,
+    /// The comments, including their parentheses.
+    pub comments: Vec<Span>,
+    /// The quoted strings of the local part, including their quotes.
+    pub quoted_strings: Vec<Span>,
+    /// The domain literal, including its brackets, if the domain is one.
+    pub domain_literal: Option<Span>,
+}
+
+impl AddressSpans {
+    /// Finds the components of `text`, a valid local part or domain starting at `offset`.
+    fn scan(&mut self, text: &str, offset: usize, is_domain: bool) {
+        for (token, range) in tokens(text) {
+            let span = Span {
+                start: offset + range.start,
+                end: offset + range.end,
+            };
+            match token {
+                Token::Comment => self.comments.push(span),
+                Token::QuotedString => self.quoted_strings.push(span),
+                Token::DomainLiteral => self.domain_literal = Some(span),
+                Token::Dot => self.dots.push(span),
+                Token::WhiteSpace => {}
+                Token::Atom if is_domain => self.domain_labels.push(span),
+                Token::Atom => self.local_part_labels.push(span),
+            }
+        }
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Parses a given string as an email address, also returning the spans of its components in
+    /// `input`, which is what [`EmailAddress::as_str`] returns too.
+    ///
+    /// Returns `None` if `input` is invalid, like [`EmailAddress::parse`].
+    ///
+    /// Not accessible from WASM.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let input = "jane.doe@mail.example.com";
+    /// let (email, spans) = EmailAddress::parse_with_spans(input, None).unwrap();
+    /// assert_eq!(&input[spans.domain.range()], email.get_domain());
+    /// assert_eq!(spans.at, Span { start: 8, end: 9 });
+    /// let labels: Vec<&str> = spans.domain_labels.iter().map(|label| &input[label.range()]).collect();
+    /// assert_eq!(labels, ["mail", "example", "com"]);
+    ///
+    /// let input = "(Jane) jane@[192.0.2.1]";
+    /// let (_, spans) = EmailAddress::parse_with_spans(input, Some(ParsingOptions::new(true))).unwrap();
+    /// assert_eq!(&input[spans.comments[0].range()], "(Jane)");
+    /// assert_eq!(&input[spans.domain_literal.unwrap().range()], "[192.0.2.1]");
+    /// ```
+    pub fn parse_with_spans(input: &str, options: Option<ParsingOptions>) -> Option<(EmailAddress, AddressSpans)> {
+        let email = EmailAddress::parse(input, options)?;
+        let at = email.get_local_part().len();
+        let mut spans = AddressSpans {
+            local_part: Span { start: 0, end: at },
+            at: Span { start: at, end: at + 1 },
+            domain: Span {
+                start: at + 1,
+                end: input.len(),
+            },
+            ..AddressSpans::default()
+        };
+        spans.scan(email.get_local_part(), 0, false);
+        spans.scan(email.get_domain(), at + 1, true);
+        Some((email, spans))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    fn components(input: &str) -> Vec<String> {
+        let (_, spans) = EmailAddress::parse_with_spans(input, Some(ParsingOptions::new(true))).unwrap();
+        let mut components: Vec<(Span, &str)> = [
+            (&spans.local_part_labels, "label"),
+            (&spans.domain_labels, "domain label"),
+            (&spans.dots, "dot"),
+            (&spans.comments, "comment"),
+            (&spans.quoted_strings, "quoted string"),
+        ]
+        .iter()
+        .flat_map(|&(spans, name)| spans.iter().map(move |&span| (span, name)))
+        .chain(spans.domain_literal.map(|span| (span, "domain literal")))
+        .chain([(spans.at, "at")])
+        .collect();
+        components.sort();
+        components
+            .iter()
+            .map(|(span, name)| alloc::format!("{} {:?}", name, &input[span.range()]))
+            .collect()
+    }
+
+    #[test]
+    fn spans_dot_atoms() {
+        assert_eq!(
+            components("jane.doe@example.com"),
+            [
+                "label \"jane\"",
+                "dot \".\"",
+                "label \"doe\"",
+                "at \"@\"",
+                "domain label \"example\"",
+                "dot \".\"",
+                "domain label \"com\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_quoted_strings_comments_and_literals() {
+        assert_eq!(
+            components("(a (nested) \\) comment) \"q\\\"s\".x@(c)[IPv6:::1] (d)"),
+            [
+                "comment \"(a (nested) \\\\) comment)\"",
+                "quoted string \"\\\"q\\\\\\\"s\\\"\"",
+                "dot \".\"",
+                "label \"x\"",
+                "at \"@\"",
+                "comment \"(c)\"",
+                "domain literal \"[IPv6:::1]\"",
+                "comment \"(d)\"",
+            ]
+        );
+        assert_eq!(
+            components("a. (c) b@x.\u{0d}\u{0a} y"),
+            [
+                "label \"a\"",
+                "dot \".\"",
+                "comment \"(c)\"",
+                "label \"b\"",
+                "at \"@\"",
+                "domain label \"x\"",
+                "dot \".\"",
+                "domain label \"y\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_cover_the_input() {
+        let input = " foö.bar @bücher.de";
+        let (email, spans) = EmailAddress::parse_with_spans(input, None).unwrap();
+        assert_eq!(&input[spans.local_part.range()], email.get_local_part());
+        assert_eq!(&input[spans.at.range()], "@");
+        assert_eq!(&input[spans.domain.range()], email.get_domain());
+        assert_eq!(&input[spans.local_part_labels[0].range()], "foö");
+        assert_eq!(spans.domain_labels[0], Span { start: 11, end: 18 });
+        assert!(EmailAddress::parse_with_spans("foo@-bar.com", None).is_none());
+    }
+}
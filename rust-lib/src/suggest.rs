@@ -0,0 +1,306 @@
+//! Suggestions for mistyped domains; e.g. `gmail.com` for `gmial.com`.
+use crate::cfws::strip_cfws;
+use crate::email_address::{EmailAddress, ParsingOptions};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Popular mailbox providers, most popular first.
+const POPULAR_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "yahoo.com",
+    "hotmail.com",
+    "outlook.com",
+    "icloud.com",
+    "aol.com",
+    "live.com",
+    "msn.com",
+    "me.com",
+    "mac.com",
+    "googlemail.com",
+    "ymail.com",
+    "protonmail.com",
+    "proton.me",
+    "comcast.net",
+    "verizon.net",
+    "att.net",
+    "sbcglobal.net",
+    "hotmail.co.uk",
+    "yahoo.co.uk",
+    "btinternet.com",
+    "gmx.com",
+    "gmx.de",
+    "gmx.net",
+    "web.de",
+    "t-online.de",
+    "orange.fr",
+    "free.fr",
+    "hotmail.fr",
+    "yahoo.fr",
+    "libero.it",
+    "mail.com",
+    "mail.ru",
+    "yandex.ru",
+    "qq.com",
+    "163.com",
+    "zoho.com",
+    "fastmail.com",
+];
+
+/// Common top-level domains, including second-level ones in common use.
+const POPULAR_TOP_LEVEL_DOMAINS: &[&str] = &[
+    "com", "net", "org", "edu", "gov", "info", "biz", "io", "co", "me", "us", "uk", "co.uk", "org.uk", "de", "fr",
+    "it", "es", "nl", "be", "ch", "at", "se", "no", "dk", "fi", "pl", "ru", "jp", "cn", "in", "br", "com.br", "au",
+    "com.au", "ca", "mx", "nz", "ie",
+];
+
+const KEYBOARD_ROWS: [(&str, f64); 4] = [("1234567890-", 0.0), ("qwertyuiop", 0.5), ("asdfghjkl;", 0.75), ("zxcvbnm,./", 1.25)];
+/// The cost of substituting a key by an adjacent one, relative to other edits.
+const ADJACENT_KEY_COST: f64 = 0.5;
+
+/// A correction of the domain of an address, suggested by [`DomainSuggester::suggest`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    /// The address with the corrected domain.
+    pub email: EmailAddress,
+    /// The typing distance between the original and the corrected domain; lower is more likely.
+    pub distance: f64,
+}
+
+/// Suggests corrections for mistyped domains, such as `gmail.com` for `gmial.com`, offline.
+///
+/// Domains are compared with an edit distance that also counts transpositions, and that
+/// weighs substitutions of adjacent keys of a QWERTY keyboard as half an edit. Domains close to
+/// one of `domains` are suggested first, then domains whose top-level domain is close to one of
+/// `top_level_domains`. Domains in `domains` are never corrected, nor are top-level domains in
+/// `top_level_domains`, and every suggestion is a valid address.
+///
+/// # Examples
+/// ```
+/// use email_address_parser::*;
+///
+/// let email = EmailAddress::parse("jane@hotmial.con", None).unwrap();
+/// let suggestions = DomainSuggester::new().suggest(&email);
+/// assert_eq!(suggestions[0].email, "jane@hotmail.com");
+///
+/// let suggester = DomainSuggester { domains: vec![String::from("example.org")], ..DomainSuggester::new() };
+/// let email = EmailAddress::parse("jane@exmaple.org", None).unwrap();
+/// assert_eq!(suggester.suggest(&email)[0].email, "jane@example.org");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainSuggester {
+    /// The known domains, most popular first; defaults to popular mailbox providers.
+    pub domains: Vec<String>,
+    /// The known top-level domains, e.g. `com` or `co.uk`.
+    pub top_level_domains: Vec<String>,
+    /// The largest distance of a suggestion; a quarter of the length of the known domain is the
+    /// limit for shorter domains, and 1 for top-level domains.
+    pub max_distance: f64,
+}
+
+impl DomainSuggester {
+    /// Instantiates a suggester with the bundled lists of popular domains and top-level domains,
+    /// and a maximum distance of 2.
+    pub fn new() -> DomainSuggester {
+        DomainSuggester {
+            domains: POPULAR_DOMAINS.iter().map(|&domain| String::from(domain)).collect(),
+            top_level_domains: POPULAR_TOP_LEVEL_DOMAINS.iter().map(|&tld| String::from(tld)).collect(),
+            max_distance: 2.0,
+        }
+    }
+
+    /// Returns the suggested corrections of the domain of `email`, most likely first.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let suggester = DomainSuggester::new();
+    /// let email = EmailAddress::parse("jane@example.con", None).unwrap();
+    /// assert_eq!(suggester.suggest(&email)[0].email, "jane@example.com");
+    ///
+    /// let email = EmailAddress::parse("jane@example.co", None).unwrap();
+    /// assert!(suggester.suggest(&email).is_empty());
+    /// ```
+    pub fn suggest(&self, email: &EmailAddress) -> Vec<Suggestion> {
+        let domain = strip_cfws(email.get_domain()).to_lowercase();
+        let is_known = |list: &[String], name: &str| list.iter().any(|known| known.eq_ignore_ascii_case(name));
+        if domain.starts_with('[') || is_known(&self.domains, &domain) {
+            return Vec::new();
+        }
+
+        // (is a known domain, distance, order in the list, domain)
+        let mut candidates: Vec<(bool, f64, usize, String)> = Vec::new();
+        for (index, known) in self.domains.iter().enumerate() {
+            let distance = typo_distance(&domain, &known.to_lowercase());
+            if distance <= self.max_distance && distance * 4.0 <= known.chars().count() as f64 {
+                candidates.push((true, distance, index, known.to_lowercase()));
+            }
+        }
+        let tld_start = |labels: usize| domain.rmatch_indices('.').nth(labels - 1).map(|(dot, _)| dot + 1);
+        let is_known_tld = [1, 2]
+            .iter()
+            .filter_map(|&labels| tld_start(labels))
+            .any(|start| is_known(&self.top_level_domains, &domain[start..]));
+        if let (false, Some(start)) = (is_known_tld, tld_start(1)) {
+            for (index, tld) in self.top_level_domains.iter().enumerate() {
+                let distance = typo_distance(&domain[start..], &tld.to_lowercase());
+                if distance <= self.max_distance.min(1.0) {
+                    let corrected = [&domain[..start], &tld.to_lowercase()].concat();
+                    candidates.push((false, distance, index, corrected));
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| (!a.0, a.1, a.2).partial_cmp(&(!b.0, b.1, b.2)).unwrap());
+        let local_part = strip_cfws(email.get_local_part());
+        let mut suggestions: Vec<Suggestion> = Vec::new();
+        for (_, distance, _, corrected) in candidates {
+            let Ok(email) = EmailAddress::new(&local_part, &corrected, Some(ParsingOptions::new(true))) else {
+                continue;
+            };
+            if suggestions.iter().all(|suggestion| suggestion.email != email) {
+                suggestions.push(Suggestion { email, distance });
+            }
+        }
+        suggestions
+    }
+}
+
+impl Default for DomainSuggester {
+    fn default() -> Self {
+        DomainSuggester::new()
+    }
+}
+
+impl EmailAddress {
+    #![warn(missing_docs)]
+    #![warn(rustdoc::missing_doc_code_examples)]
+
+    /// Suggests corrections of a mistyped domain against the bundled lists of popular domains and
+    /// top-level domains, most likely first; use [`DomainSuggester`] to provide other lists.
+    ///
+    /// # Examples
+    /// ```
+    /// use email_address_parser::*;
+    ///
+    /// let email = EmailAddress::parse("jane@gmial.com", None).unwrap();
+    /// assert_eq!(email.suggest_corrections()[0].email, "jane@gmail.com");
+    ///
+    /// let email = EmailAddress::parse("jane@gmail.com", None).unwrap();
+    /// assert!(email.suggest_corrections().is_empty());
+    /// ```
+    pub fn suggest_corrections(&self) -> Vec<Suggestion> {
+        DomainSuggester::new().suggest(self)
+    }
+}
+
+/// Returns the position of `key` on a QWERTY keyboard, rows being 1 apart.
+fn key_position(key: char) -> Option<(f64, f64)> {
+    KEYBOARD_ROWS.iter().enumerate().find_map(|(row, (keys, offset))| {
+        let column = keys.find(key)?;
+        Some((offset + column as f64, row as f64))
+    })
+}
+
+fn substitution_cost(a: char, b: char) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    match (key_position(a), key_position(b)) {
+        (Some((ax, ay)), Some((bx, by))) if (ay - by) * (ay - by) <= 1.0 && (ax - bx) * (ax - bx) <= 1.0 => {
+            ADJACENT_KEY_COST
+        }
+        _ => 1.0,
+    }
+}
+
+/// Returns the optimal string alignment distance between `a` and `b`, with keyboard-weighted substitutions.
+fn typo_distance(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let width = b.len() + 1;
+    let mut distances: Vec<f64> = (0..(a.len() + 1) * width).map(|_| 0.0).collect();
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            distances[i * width + j] = if i == 0 || j == 0 {
+                (i + j) as f64
+            } else {
+                let mut distance = (distances[(i - 1) * width + j] + 1.0)
+                    .min(distances[i * width + j - 1] + 1.0)
+                    .min(distances[(i - 1) * width + j - 1] + substitution_cost(a[i - 1], b[j - 1]));
+                if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    distance = distance.min(distances[(i - 2) * width + j - 2] + 1.0);
+                }
+                distance
+            };
+        }
+    }
+    distances[a.len() * width + b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggest(address: &str) -> Vec<String> {
+        let email = EmailAddress::parse(address, None).unwrap();
+        email.suggest_corrections().iter().map(|suggestion| suggestion.email.to_string()).collect()
+    }
+
+    #[test]
+    fn weighs_typos() {
+        assert_eq!(typo_distance("gmial.com", "gmail.com"), 1.0);
+        assert_eq!(typo_distance("gmail.con", "gmail.com"), ADJACENT_KEY_COST);
+        assert_eq!(typo_distance("gmail.cpm", "gmail.com"), ADJACENT_KEY_COST);
+        assert_eq!(typo_distance("gmail.cxm", "gmail.com"), 1.0);
+        assert_eq!(typo_distance("gmai.com", "gmail.com"), 1.0);
+        assert_eq!(typo_distance("gmaill.com", "gmail.com"), 1.0);
+        assert_eq!(typo_distance("", "abc"), 3.0);
+    }
+
+    #[test]
+    fn suggests_popular_domains_first() {
+        let cases = [
+            ("a@gmial.com", "a@gmail.com"),
+            ("a@gmai.com", "a@gmail.com"),
+            ("a@GMAIL.CON", "a@gmail.com"),
+            ("a@hotmial.con", "a@hotmail.com"),
+            ("a@yahoo.co", "a@yahoo.com"),
+            ("a@yaho.com", "a@yahoo.com"),
+            ("a@outlok.com", "a@outlook.com"),
+            ("a@iclod.com", "a@icloud.com"),
+            ("a@example.cmo", "a@example.com"),
+            ("a@example.co.ukk", "a@example.co.uk"),
+        ];
+        for (address, expected) in cases {
+            assert_eq!(suggest(address).first().map(String::as_str), Some(expected), "{:?}", address);
+        }
+    }
+
+    #[test]
+    fn keeps_known_and_distant_domains() {
+        for address in ["a@gmail.com", "a@Hotmail.com", "a@example.com", "a@example.co.uk", "a@company.io", "a@[127.0.0.1]"] {
+            assert_eq!(suggest(address), Vec::<String>::new(), "{:?}", address);
+        }
+        let suggester = DomainSuggester {
+            max_distance: 0.5,
+            ..DomainSuggester::new()
+        };
+        let email = EmailAddress::parse("a@gmial.com", None).unwrap();
+        assert!(suggester.suggest(&email).is_empty());
+    }
+
+    #[test]
+    fn ignores_comments_and_white_space() {
+        let lax_suggest = |address| {
+            let email = EmailAddress::parse(address, Some(ParsingOptions::new(true))).unwrap();
+            email.suggest_corrections().iter().map(|suggestion| suggestion.email.to_string()).collect::<Vec<_>>()
+        };
+        let expected = suggest("jane@gmial.com");
+        assert_eq!(expected[0], "jane@gmail.com");
+        for address in ["jane@gmial.com ", "jane@(c)gmial.com", "(x) jane @ gmial.com"] {
+            assert_eq!(lax_suggest(address), expected, "{:?}", address);
+        }
+        assert_eq!(lax_suggest("jane@gmail.com\r\n "), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,487 @@
+use crate::email_address::{EmailAddress, ParsingOptions};
+use crate::nom_parser;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use wasm_bindgen::prelude::*;
+
+/// JS-side helpers for errors and results.
+#[wasm_bindgen(inline_js = r#"
+export function asError(error) {
+  const prototype = Object.getPrototypeOf(error);
+  if (Object.getPrototypeOf(prototype) !== Error.prototype) {
+    Object.setPrototypeOf(prototype, Error.prototype);
+  }
+  Object.defineProperty(error, "stack", { value: new Error(error.message).stack, configurable: true, writable: true });
+  return error;
+}
+
+export function span(start, end) {
+  return { start, end };
+}
+
+export function okResult(value) {
+  return { ok: true, value };
+}
+
+export function errorResult(error) {
+  return { ok: false, error };
+}
+
+export function batchText(inputs) {
+  let text = "";
+  for (const input of inputs) {
+    // The separator keeps a lone surrogate from pairing with one in the next input.
+    if (typeof input === "string") text += input;
+    text += "\0";
+  }
+  return text;
+}
+
+export function batchLengths(inputs) {
+  const lengths = new Uint32Array(inputs.length);
+  for (let i = 0; i < inputs.length; i++) {
+    const input = inputs[i];
+    lengths[i] = typeof input === "string" ? input.length : 0;
+  }
+  return lengths;
+}
+"#)]
+extern "C" {
+    /// Makes the exported `EmailAddressParseError` class inherit from `Error`, and gives `error` a stack trace.
+    #[wasm_bindgen(js_name = "asError")]
+    fn as_error(error: EmailAddressParseError) -> JsValue;
+
+    fn span(start: usize, end: usize) -> JsValue;
+
+    #[wasm_bindgen(js_name = "okResult")]
+    fn ok_result(value: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_name = "errorResult")]
+    fn error_result(error: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_name = "batchText")]
+    fn batch_text(inputs: &JsValue) -> String;
+
+    #[wasm_bindgen(js_name = "batchLengths")]
+    fn batch_lengths(inputs: &JsValue) -> Vec<u32>;
+}
+
+/// Error codes reported by `EmailAddress.validateManyWithErrorCodes`, one per input.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorCode {
+    /// The input is a valid email address.
+    Valid = 0,
+    /// The input is empty, or is not a string.
+    Empty = 1,
+    /// The local part is invalid.
+    InvalidLocalPart = 2,
+    /// There is no `@` separating the local part from the domain.
+    MissingAt = 3,
+    /// The domain is invalid.
+    InvalidDomain = 4,
+    /// The input is not valid UTF-8; never reported for JavaScript strings.
+    InvalidUtf8 = 5,
+}
+
+impl From<ParseErrorKind> for ParseErrorCode {
+    fn from(kind: ParseErrorKind) -> Self {
+        match kind {
+            ParseErrorKind::Empty => ParseErrorCode::Empty,
+            ParseErrorKind::InvalidLocalPart => ParseErrorCode::InvalidLocalPart,
+            ParseErrorKind::MissingAt => ParseErrorCode::MissingAt,
+            ParseErrorKind::InvalidDomain => ParseErrorCode::InvalidDomain,
+            ParseErrorKind::InvalidUtf8 => ParseErrorCode::InvalidUtf8,
+        }
+    }
+}
+
+/// Splits the concatenation of all inputs, built by `batchText`, by their UTF-16 lengths from `batchLengths`.
+///
+/// Each input is followed by a NUL separator in `text`, which is skipped.
+struct Batch<'a> {
+    text: &'a str,
+    lengths: std::slice::Iter<'a, u32>,
+}
+
+impl<'a> Iterator for Batch<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut units = *self.lengths.next()? as usize;
+        let mut end = 0;
+        for ch in self.text.chars() {
+            if units == 0 {
+                break;
+            }
+            // Lone surrogates are replaced by U+FFFD on the way in, which keeps their length of one unit.
+            units = units.saturating_sub(ch.len_utf16());
+            end += ch.len_utf8();
+        }
+        let (input, rest) = self.text.split_at(end);
+        self.text = rest.strip_prefix('\0').unwrap_or(rest);
+        Some(input)
+    }
+}
+
+/// Validates every string of `inputs` with a single copy of all of them into wasm memory, mapping each one through `code`.
+fn validate_batch(inputs: &JsValue, options: Option<ParsingOptions>, code: impl Fn(&str, bool) -> u8) -> Vec<u8> {
+    let is_lax = options.unwrap_or_default().is_lax;
+    let text = batch_text(inputs);
+    let lengths = batch_lengths(inputs);
+    Batch {
+        text: &text,
+        lengths: lengths.iter(),
+    }
+    .map(|input| code(input, is_lax))
+    .collect()
+}
+
+fn kind_name(kind: ParseErrorKind) -> &'static str {
+    match kind {
+        ParseErrorKind::Empty => "empty",
+        ParseErrorKind::InvalidLocalPart => "invalidLocalPart",
+        ParseErrorKind::MissingAt => "missingAt",
+        ParseErrorKind::InvalidDomain => "invalidDomain",
+        ParseErrorKind::InvalidUtf8 => "invalidUtf8",
+    }
+}
+
+/// Error thrown by the `EmailAddress` constructor and `EmailAddress.fromJSON`, and returned by `EmailAddress.tryParse`, for invalid input.
+/// It is an `Error`, and can be tested with `instanceof EmailAddressParseError`.
+///
+/// @example
+/// ```ts
+/// const result = EmailAddress.tryParse(`foo@-bar.com`);
+/// assert(!result.ok && result.error instanceof EmailAddressParseError);
+/// ```
+#[wasm_bindgen]
+pub struct EmailAddressParseError {
+    kind: ParseErrorKind,
+    message: String,
+    span: (usize, usize),
+    utf16_span: (usize, usize),
+}
+
+#[wasm_bindgen]
+impl EmailAddressParseError {
+    /// Always `"EmailAddressParseError"`.
+    #[wasm_bindgen(getter, unchecked_return_type = "\"EmailAddressParseError\"")]
+    pub fn name(&self) -> String {
+        String::from("EmailAddressParseError")
+    }
+
+    /// A description of the error, including the UTF-8 byte offset at which it was detected.
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// The reason why the input is invalid.
+    #[wasm_bindgen(getter, unchecked_return_type = "ParseErrorKind")]
+    pub fn kind(&self) -> String {
+        String::from(kind_name(self.kind))
+    }
+
+    /// The UTF-8 byte offset into the input at which the error was detected.
+    #[wasm_bindgen(getter)]
+    pub fn offset(&self) -> usize {
+        self.span.0
+    }
+
+    /// The offset into the input at which the error was detected, in UTF-16 code units; i.e. usable with `input.slice`.
+    #[wasm_bindgen(getter = utf16Offset)]
+    pub fn utf16_offset(&self) -> usize {
+        self.utf16_span.0
+    }
+
+    /// The offending character, as UTF-8 byte offsets into the input; empty if the input ended unexpectedly.
+    #[wasm_bindgen(getter, unchecked_return_type = "Span")]
+    pub fn span(&self) -> JsValue {
+        span(self.span.0, self.span.1)
+    }
+
+    /// The offending character, in UTF-16 code units; `input.slice(utf16Span.start, utf16Span.end)` is the character to highlight.
+    #[wasm_bindgen(getter = utf16Span, unchecked_return_type = "Span")]
+    pub fn utf16_span(&self) -> JsValue {
+        span(self.utf16_span.0, self.utf16_span.1)
+    }
+}
+
+/// Converts a UTF-8 byte offset into `input` to UTF-16 code units, as used to index JS strings.
+fn utf16_offset(input: &str, offset: usize) -> usize {
+    input[..offset].encode_utf16().count()
+}
+
+/// Converts `error` for `input` to an `EmailAddressParseError`, spanning the offending character.
+fn to_js_error(input: &str, error: &ParseError) -> JsValue {
+    let start = error.offset();
+    let end = start + input[start..].chars().next().map_or(0, char::len_utf8);
+    as_error(EmailAddressParseError {
+        kind: error.kind(),
+        message: error.to_string(),
+        span: (start, end),
+        utf16_span: (utf16_offset(input, start), utf16_offset(input, end)),
+    })
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+/**
+ * The reason why an input is not a valid email address.
+ */
+export type ParseErrorKind = "empty" | "invalidLocalPart" | "missingAt" | "invalidDomain" | "invalidUtf8";
+
+/**
+ * `EmailAddressParseError` inherits from `Error`.
+ */
+export interface EmailAddressParseError extends Error {}
+
+/**
+ * A half-open range `[start, end)` of a string.
+ */
+export interface Span {
+  readonly start: number;
+  readonly end: number;
+}
+
+/**
+ * Result of `EmailAddress.tryParse`.
+ */
+export type ParseResult =
+  | { readonly ok: true; readonly value: EmailAddress }
+  | { readonly ok: false; readonly error: EmailAddressParseError };
+"#;
+
+/// Options for parsing.
+#[wasm_bindgen]
+impl ParsingOptions {
+    /// Instantiates `ParsingOptions`.
+    /// @param is_lax Can be set to `true` or `false` to enable/disable obsolete parts parsing.
+    #[doc(hidden)]
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(is_lax: bool) -> ParsingOptions {
+        ParsingOptions::new(is_lax)
+    }
+
+    /// Returns the default options; i.e. strict parsing, where obsolete parts as defined by RFC 5322 are not allowed.
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "default")]
+    pub fn js_default() -> ParsingOptions {
+        ParsingOptions::default()
+    }
+}
+
+#[wasm_bindgen]
+impl EmailAddress {
+    /// Instantiates a new `EmailAddress`.
+    /// It throws an `EmailAddressParseError` if either the local part or domain is invalid and cannot be parsed;
+    /// the `offset` of the error then refers to the string `${localPart}@${domain}`.
+    /// @param options When not provided, the default options is used. That comprised of strict parsing; i.e. obsolete parts as defined by RFC 5322 are not allowed.
+    ///
+    /// @example
+    /// ```ts
+    /// const email = new EmailAddress("foo", "bar.com");
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(
+        local_part: &str,
+        domain: &str,
+        options: Option<ParsingOptions>,
+    ) -> Result<EmailAddress, JsValue> {
+        EmailAddress::new(local_part, domain, options.clone()).map_err(|_| {
+            let address = format!("{}@{}", local_part, domain);
+            let is_lax = options.unwrap_or_default().is_lax;
+            to_js_error(&address, &nom_parser::diagnose_address(&address, is_lax))
+        })
+    }
+
+    /// Parses a given string as an email address.
+    /// @param input The input to parse.
+    /// @param options When not provided, the default options is used. That comprised of strict parsing; i.e. obsolete parts as defined by RFC 5322 are not allowed.
+    /// @returns An instance `EmailAddress` if the input is valid, else `undefined`.
+    ///
+    /// @example
+    /// ```ts
+    /// const email = EmailAddress.parse(`foo@bar.com`);
+    /// assert(email.localPart === "foo");
+    /// assert(email.domain === "bar.com");
+    ///
+    /// assert(EmailAddress.parse(`foo@-bar.com`, new ParsingOptions(true)) === undefined);
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "parse")]
+    pub fn js_parse(input: &str, options: Option<ParsingOptions>) -> Option<EmailAddress> {
+        EmailAddress::parse(input, options)
+    }
+
+    /// Parses a given string as an email address without throwing.
+    /// @param input The input to parse.
+    /// @param options When not provided, the default options is used.
+    /// @returns `{ ok: true, value }` if the input is valid, else `{ ok: false, error }` explaining why it is not.
+    ///
+    /// @example
+    /// ```ts
+    /// const result = EmailAddress.tryParse(`foo@-bar.com`);
+    /// if (!result.ok) {
+    ///   assert(result.error.kind === "invalidDomain");
+    ///   assert(result.error.offset === 4);
+    /// }
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "tryParse", unchecked_return_type = "ParseResult")]
+    pub fn js_try_parse(input: &str, options: Option<ParsingOptions>) -> JsValue {
+        match EmailAddress::try_parse(input, options) {
+            Ok(email) => ok_result(email.into()),
+            Err(error) => error_result(to_js_error(input, &error)),
+        }
+    }
+
+    /// Validates if the given `input` string is an email address or not.
+    /// Unlike the `parse` method, it does not instantiate an `EmailAddress`.
+    /// @param input The string to validate.
+    /// @param options When not provided, the default options is used.
+    /// @returns `true` if the `input` is valid, `false` otherwise.
+    ///
+    /// @example
+    /// ```ts
+    /// assert(EmailAddress.isValid(`foo@bar.com`));
+    /// assert(!EmailAddress.isValid(`foo@-bar.com`, new ParsingOptions(true)));
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn js_is_valid(input: &str, options: Option<ParsingOptions>) -> bool {
+        EmailAddress::is_valid(input, options)
+    }
+
+    /// Validates every string of `inputs` in a single call; non-string items are reported as invalid.
+    /// It is considerably faster than calling `isValid` for every item of a large batch.
+    /// @param inputs The strings to validate.
+    /// @param options When not provided, the default options is used.
+    /// @returns A `Uint8Array` with, at the position of every input, `1` if it is valid and `0` otherwise.
+    ///
+    /// @example
+    /// ```ts
+    /// const results = EmailAddress.validateMany([`foo@bar.com`, `foo@-bar.com`]);
+    /// assert.deepEqual([...results], [1, 0]);
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "validateMany")]
+    pub fn js_validate_many(
+        #[wasm_bindgen(unchecked_param_type = "string[]")] inputs: &JsValue,
+        options: Option<ParsingOptions>,
+    ) -> Vec<u8> {
+        validate_batch(inputs, options, |input, is_lax| {
+            nom_parser::parse_address(input, is_lax).is_some() as u8
+        })
+    }
+
+    /// Like `validateMany`, but reports why every invalid input is not an email address.
+    /// @param inputs The strings to validate.
+    /// @param options When not provided, the default options is used.
+    /// @returns A `Uint8Array` with, at the position of every input, a `ParseErrorCode`; `ParseErrorCode.Valid` (`0`) for valid inputs.
+    ///
+    /// @example
+    /// ```ts
+    /// const codes = EmailAddress.validateManyWithErrorCodes([`foo@bar.com`, `foo@-bar.com`]);
+    /// assert.deepEqual([...codes], [ParseErrorCode.Valid, ParseErrorCode.InvalidDomain]);
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "validateManyWithErrorCodes")]
+    pub fn js_validate_many_with_error_codes(
+        #[wasm_bindgen(unchecked_param_type = "string[]")] inputs: &JsValue,
+        options: Option<ParsingOptions>,
+    ) -> Vec<u8> {
+        validate_batch(inputs, options, |input, is_lax| {
+            let code = match nom_parser::parse_address(input, is_lax) {
+                Some(_) => ParseErrorCode::Valid,
+                None => nom_parser::diagnose_address(input, is_lax).kind().into(),
+            };
+            code as u8
+        })
+    }
+
+    /// Re-creates an `EmailAddress` from the output of `toJSON`, parsing it with the given options.
+    /// It throws an `EmailAddressParseError` if the value is not a valid email address.
+    ///
+    /// @example
+    /// ```ts
+    /// const email = EmailAddress.fromJSON(JSON.parse(JSON.stringify(EmailAddress.parse(`foo@bar.com`))));
+    /// assert(email.domain === "bar.com");
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "fromJSON")]
+    pub fn js_from_json(value: &str, options: Option<ParsingOptions>) -> Result<EmailAddress, JsValue> {
+        EmailAddress::try_parse(value, options).map_err(|error| to_js_error(value, &error))
+    }
+
+    /// The local part of the email address.
+    #[doc(hidden)]
+    #[wasm_bindgen(getter = localPart)]
+    pub fn js_local_part(&self) -> String {
+        self.localPart()
+    }
+
+    /// The domain of the email address.
+    #[doc(hidden)]
+    #[wasm_bindgen(getter = domain)]
+    pub fn js_domain(&self) -> String {
+        self.domain()
+    }
+
+    /// The UTF-8 byte range of the local part within `toString()`.
+    #[doc(hidden)]
+    #[wasm_bindgen(getter = localPartSpan, unchecked_return_type = "Span")]
+    pub fn js_local_part_span(&self) -> JsValue {
+        span(0, self.get_local_part().len())
+    }
+
+    /// The UTF-8 byte range of the domain within `toString()`.
+    #[doc(hidden)]
+    #[wasm_bindgen(getter = domainSpan, unchecked_return_type = "Span")]
+    pub fn js_domain_span(&self) -> JsValue {
+        span(self.as_str().len() - self.get_domain().len(), self.as_str().len())
+    }
+
+    /// The range of the local part within `toString()` in UTF-16 code units; i.e. usable with `String.prototype.slice`.
+    #[doc(hidden)]
+    #[wasm_bindgen(getter = localPartUtf16Span, unchecked_return_type = "Span")]
+    pub fn js_local_part_utf16_span(&self) -> JsValue {
+        span(0, utf16_offset(self.as_str(), self.get_local_part().len()))
+    }
+
+    /// The range of the domain within `toString()` in UTF-16 code units; i.e. usable with `String.prototype.slice`.
+    #[doc(hidden)]
+    #[wasm_bindgen(getter = domainUtf16Span, unchecked_return_type = "Span")]
+    pub fn js_domain_utf16_span(&self) -> JsValue {
+        let address = self.as_str();
+        let start = address.len() - self.get_domain().len();
+        span(utf16_offset(address, start), utf16_offset(address, address.len()))
+    }
+
+    /// Returns `true` if `other` is the same email address, `false` otherwise.
+    ///
+    /// @example
+    /// ```ts
+    /// assert(new EmailAddress("foo", "bar.com").equals(EmailAddress.parse(`foo@bar.com`)));
+    /// ```
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "equals")]
+    pub fn js_equals(&self, other: &EmailAddress) -> bool {
+        self == other
+    }
+
+    /// Returns the email address as a string; used by `JSON.stringify`.
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "toJSON")]
+    pub fn js_to_json(&self) -> String {
+        self.toString()
+    }
+
+    /// Returns the email address as a string.
+    #[doc(hidden)]
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn js_to_string(&self) -> String {
+        self.toString()
+    }
+}
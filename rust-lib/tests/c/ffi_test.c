@@ -0,0 +1,67 @@
+/* Exercises the C API through the generated header; run by tests/ffi_tests.rs. */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "email_address_parser.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                  \
+  do {                                                                    \
+    if (!(condition)) {                                                   \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+      failures++;                                                         \
+    }                                                                     \
+  } while (0)
+
+static int str_equals(EapStr actual, const char *expected) {
+  return actual.len == strlen(expected) && memcmp(actual.ptr, expected, actual.len) == 0;
+}
+
+int main(void) {
+  EapParsingOptions strict = eap_parsing_options_default();
+  EapParsingOptions lax = {.is_lax = true};
+  EapEmailAddress *address = NULL;
+
+  CHECK(!strict.is_lax);
+
+  CHECK(eap_parse("foo@bar.com", NULL, &address) == EAP_STATUS_OK);
+  CHECK(address != NULL);
+  CHECK(str_equals(eap_email_address_local_part(address), "foo"));
+  CHECK(str_equals(eap_email_address_domain(address), "bar.com"));
+  CHECK(str_equals(eap_email_address_as_str(address), "foo@bar.com"));
+  char *string = eap_email_address_to_string(address);
+  CHECK(string != NULL && strcmp(string, "foo@bar.com") == 0);
+  eap_string_free(string);
+  eap_email_address_free(address);
+
+  address = NULL;
+  CHECK(eap_parse("test@-iana.org", &lax, &address) == EAP_STATUS_INVALID_ADDRESS);
+  CHECK(address == NULL);
+  CHECK(eap_parse(NULL, NULL, &address) == EAP_STATUS_NULL_POINTER);
+  CHECK(eap_parse("f\xff@bar.com", NULL, &address) == EAP_STATUS_INVALID_UTF8);
+  CHECK(strcmp(eap_status_message(EAP_STATUS_INVALID_ADDRESS), "invalid email address") == 0);
+  CHECK(strcmp(eap_status_message(42), "unknown status") == 0);
+
+  CHECK(eap_is_valid("fo\xc3\xb6@b\xc3\xbc" "cher.de", &strict));
+  CHECK(!eap_is_valid("\r\n test@iana.org", &strict));
+  CHECK(eap_is_valid("\r\n test@iana.org", &lax));
+  CHECK(!eap_is_valid(NULL, NULL));
+
+  CHECK(eap_new("foo", "bar.com", NULL, &address) == EAP_STATUS_OK);
+  CHECK(str_equals(eap_email_address_as_str(address), "foo@bar.com"));
+  eap_email_address_free(address);
+  address = NULL;
+  CHECK(eap_new("foo", "-bar.com", &strict, &address) == EAP_STATUS_INVALID_ADDRESS);
+  CHECK(address == NULL);
+
+  eap_email_address_free(NULL);
+  eap_string_free(NULL);
+
+  if (failures > 0) {
+    fprintf(stderr, "%d check(s) failed\n", failures);
+    return EXIT_FAILURE;
+  }
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "cli")]
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_email-address-parser"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn exits_with_zero_for_valid_addresses() {
+    let output = run(&["foo@bar.com", "foö@bücher.de"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "arg:1: PASS foo@bar.com\narg:2: PASS foö@bücher.de\n");
+}
+
+#[test]
+fn exits_with_one_and_reports_reason_for_invalid_addresses() {
+    let output = run(&["foo@bar.com", "foo@-bar.com", "foo.@bar.com", "foo"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "arg:1: PASS foo@bar.com\n\
+         arg:2: FAIL foo@-bar.com (invalid domain at offset 4)\n\
+         arg:3: FAIL foo.@bar.com (invalid local part at offset 4)\n\
+         arg:4: FAIL foo (missing '@' at offset 3)\n"
+    );
+}
+
+#[test]
+fn lax_option_allows_obsolete_syntax() {
+    let output = run(&["test . test@iana.org"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("valid only with --lax"));
+
+    let output = run(&["--lax", "test . test@iana.org"], "");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn reads_lines_from_stdin_as_json() {
+    let output = run(&["-o", "json", "--only-invalid"], "foo@bar.com\r\n\n\"foo\\\"@bar.com\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "{\"source\":\"-\",\"line\":3,\"address\":\"\\\"foo\\\\\\\"@bar.com\",\"valid\":false,\"reason\":\"invalid local part at offset 14\"}\n"
+    );
+}
+
+#[test]
+fn validates_csv_column_by_name_and_index() {
+    let input = "name,email\nfoo,foo@bar.com\nbar,\"bar@-bar.com\"\n";
+    let output = run(&["--column", "email", "-o", "csv"], input);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "source,line,address,valid,reason\n-,2,foo@bar.com,true,\n-,3,bar@-bar.com,false,invalid domain at offset 4\n"
+    );
+
+    let output = run(&["--column", "2", "--no-header", "-q"], "foo,foo@bar.com\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn reports_missing_csv_columns_and_invalid_utf8() {
+    let output = run(&["--column", "2", "--no-header", "-o", "csv"], "foo,foo@bar.com\nbar\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "source,line,address,valid,reason\n-,1,foo@bar.com,true,\n-,2,,false,missing column 2\n"
+    );
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_email-address-parser"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"fo\xf6@bar.com\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "-:1: FAIL fo\u{fffd}@bar.com (invalid UTF-8 at offset 2)\n");
+}
+
+#[test]
+fn exits_with_two_on_errors() {
+    assert_eq!(run(&["--file", "does-not-exist.txt"], "").status.code(), Some(2));
+    assert_eq!(run(&["--column", "missing"], "email\nfoo@bar.com\n").status.code(), Some(2));
+    assert_eq!(run(&["--lax", "--strict"], "").status.code(), Some(2));
+}
@@ -0,0 +1,74 @@
+#![cfg(all(feature = "ffi", unix))]
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+// The cdylib is placed next to the test executables by cargo.
+fn cdylib_dir() -> PathBuf {
+    env::current_exe().unwrap().parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn generated_header_is_up_to_date() {
+    let crate_dir = manifest_dir();
+    let header_path = crate_dir.join("include").join("email_address_parser.h");
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::generate_with_config(&crate_dir, config)
+        .unwrap()
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    // Set `UPDATE_C_HEADER=1` to regenerate the checked-in header.
+    if env::var_os("UPDATE_C_HEADER").is_some() {
+        fs::write(&header_path, &generated).unwrap();
+    }
+    assert_eq!(
+        fs::read_to_string(&header_path).unwrap(),
+        generated,
+        "{} is stale; rerun the tests with UPDATE_C_HEADER=1",
+        header_path.display()
+    );
+}
+
+#[test]
+fn c_program_links_and_passes() {
+    let crate_dir = manifest_dir();
+    let lib_dir = cdylib_dir();
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let program = out_dir.join("ffi_test");
+    let compiler = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+
+    let status = Command::new(&compiler)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests").join("c").join("ffi_test.c"))
+        .arg("-o")
+        .arg(&program)
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-lemail_address_parser")
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run C compiler '{}': {}", compiler, e));
+    assert!(status.success(), "compiling the C test program failed");
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .env("DYLD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "C test program failed:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(any(feature = "arbitrary", feature = "proptest"))]
+use email_address_parser::{AddressGenerator, EmailAddress};
+
+#[cfg(feature = "proptest")]
+mod properties {
+    use super::*;
+    use email_address_parser::ParsingOptions;
+    use proptest::prelude::*;
+
+    fn conventional(is_lax: bool) -> AddressGenerator {
+        AddressGenerator {
+            quoted_strings: false,
+            comments: false,
+            folding_whitespace: false,
+            domain_literals: false,
+            unicode: false,
+            ..AddressGenerator::new(is_lax)
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn strict_addresses_round_trip(address in AddressGenerator::new(false).strategy()) {
+            let email = EmailAddress::parse(&address, None);
+            prop_assert!(email.is_some(), "{:?} is invalid", address);
+            prop_assert_eq!(email.unwrap().to_string(), address);
+        }
+
+        #[test]
+        fn lax_addresses_round_trip(address in AddressGenerator::new(true).strategy()) {
+            let email = EmailAddress::parse(&address, Some(ParsingOptions::new(true)));
+            prop_assert!(email.is_some(), "{:?} is invalid", address);
+            prop_assert_eq!(email.unwrap().to_string(), address);
+        }
+
+        #[test]
+        fn strict_addresses_are_valid_with_lax_parsing(address in AddressGenerator::new(false).strategy()) {
+            prop_assert!(EmailAddress::is_valid(&address, Some(ParsingOptions::new(true))));
+        }
+
+        #[test]
+        fn components_round_trip(email in any::<EmailAddress>()) {
+            let rebuilt = EmailAddress::new(email.get_local_part(), email.get_domain(), None).ok();
+            prop_assert_eq!(rebuilt, Some(email));
+        }
+
+        #[test]
+        fn conventional_addresses_are_plain(address in conventional(false).strategy()) {
+            prop_assert!(address.is_ascii());
+            prop_assert!(!address.contains([' ', '\t', '\r', '"', '(', '[']));
+            prop_assert!(EmailAddress::is_valid(&address, None));
+        }
+
+        #[test]
+        fn conventional_lax_addresses_round_trip(address in conventional(true).strategy()) {
+            let email = EmailAddress::parse(&address, Some(ParsingOptions::new(true)));
+            prop_assert_eq!(email.map(|email| email.to_string()), Some(address));
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_generates_valid_addresses() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let mut state = 0x2545_f491_u32;
+    let data: Vec<u8> = (0..1 << 16)
+        .map(|_| {
+            // xorshift32
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect();
+    let mut u = Unstructured::new(&data);
+    let lax = AddressGenerator::new(true);
+    while !u.is_empty() {
+        let email = EmailAddress::arbitrary(&mut u).unwrap();
+        assert!(EmailAddress::is_valid(email.as_str(), None));
+        let address = lax.arbitrary_address(&mut u).unwrap();
+        let email = EmailAddress::parse(&address, Some(lax.parsing_options()));
+        assert_eq!(email.map(|email| email.to_string()), Some(address));
+    }
+}
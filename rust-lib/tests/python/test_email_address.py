@@ -0,0 +1,73 @@
+# Python-side counterpart of tests/email_address_tests.rs.
+# Run with `maturin develop && python -m pytest tests/python` (or `python -m unittest discover tests/python`).
+import copy
+import unittest
+
+from email_address_parser import EmailAddress, ParsingOptions
+
+
+class EmailAddressTests(unittest.TestCase):
+    def test_clone(self):
+        expected = EmailAddress("foo", "bar.com")
+        actual = copy.copy(expected)
+
+        # check they are the same
+        self.assertEqual(expected, actual)
+
+        # ensure it exists after the source is dropped
+        del expected
+        self.assertEqual("foo", actual.local_part)
+        self.assertEqual("bar.com", actual.domain)
+
+    def test_parse(self):
+        email = EmailAddress.parse("foo@bar.com")
+        self.assertIsNotNone(email)
+        self.assertEqual(email.local_part, "foo")
+        self.assertEqual(email.domain, "bar.com")
+        self.assertEqual(str(email), "foo@bar.com")
+
+        self.assertIsNone(EmailAddress.parse("test@-iana.org", ParsingOptions(True)))
+        self.assertIsNone(EmailAddress.parse("\r\n \r\n test@iana.org"))
+        self.assertIsNotNone(EmailAddress.parse("\r\n \r\n test@iana.org", ParsingOptions(is_lax=True)))
+
+    def test_unicode(self):
+        self.assertEqual(str(EmailAddress("foö", "bücher.de")), "foö@bücher.de")
+        self.assertEqual(str(EmailAddress.parse("foö@bücher.de")), "foö@bücher.de")
+        self.assertTrue(EmailAddress.is_valid("foö@bücher.de"))
+
+    def test_is_valid(self):
+        self.assertTrue(EmailAddress.is_valid("foo@bar.com"))
+        self.assertTrue(EmailAddress.is_valid("\r\n \r\n test@iana.org", ParsingOptions(True)))
+        self.assertFalse(EmailAddress.is_valid("test@-iana.org", ParsingOptions(True)))
+        self.assertFalse(EmailAddress.is_valid("test"))
+
+    def test_new_raises_for_invalid_parts(self):
+        with self.assertRaises(ValueError):
+            EmailAddress("foo", "-bar.com")
+        email = EmailAddress("", "", ParsingOptions(True))
+        self.assertEqual(email.local_part, "")
+        self.assertEqual(email.domain, "")
+
+    def test_equality_and_hashing(self):
+        foo_at_bar_dot_com = EmailAddress("foo", "bar.com")
+        foob_at_ar_dot_com = EmailAddress("foob", "ar.com")
+        self.assertEqual(foo_at_bar_dot_com, EmailAddress.parse("foo@bar.com"))
+        self.assertNotEqual(foo_at_bar_dot_com, foob_at_ar_dot_com)
+        self.assertEqual(len({foo_at_bar_dot_com, EmailAddress("foo", "bar.com"), foob_at_ar_dot_com}), 2)
+
+    def test_validate_many(self):
+        inputs = ["foo@bar.com", "test@ iana .com", "test@-iana.org", None, float("nan")]
+        self.assertEqual(EmailAddress.validate_many(inputs), [True, False, False, False, False])
+        self.assertEqual(
+            EmailAddress.validate_many(iter(inputs), ParsingOptions(True)),
+            [True, True, False, False, False],
+        )
+
+    def test_parsing_options(self):
+        self.assertFalse(ParsingOptions().is_lax)
+        self.assertTrue(ParsingOptions(True).is_lax)
+        self.assertEqual(ParsingOptions(True), ParsingOptions(is_lax=True))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,236 @@
+#![cfg(not(target_arch = "wasm32"))]
+use email_address_parser::{EmailAddress, ParsingOptions, RegexDialect, RegexSource};
+use regex::Regex;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn anchored(source: &str) -> Regex {
+    Regex::new(&format!("^(?:{})$", source)).unwrap()
+}
+
+// The corpus files hold escaped Rust string literal fragments.
+fn read_corpus(file_name: &str) -> Vec<String> {
+    fs::read_to_string(manifest_dir().join("..").join(".test_data").join(file_name))
+        .unwrap()
+        .lines()
+        .map(|line| line.replace("\\\"", "\"").replace("\\\\", "\\"))
+        .collect()
+}
+
+// Control characters are stored as their Unicode symbols (U+2400 onwards) in the XML file.
+fn read_is_email_addresses() -> Vec<String> {
+    let xml = fs::read_to_string(manifest_dir().join("..").join(".test_data").join("isemail_tests.xml")).unwrap();
+    xml.split("<address>")
+        .skip(1)
+        .map(|rest| {
+            let mut address = String::new();
+            let mut text = &rest[..rest.find("</address>").unwrap()];
+            while let Some(start) = text.find('&') {
+                address.push_str(&text[..start]);
+                let end = start + text[start..].find(';').unwrap();
+                let ch = match &text[start + 1..end] {
+                    "amp" => '&',
+                    "lt" => '<',
+                    "gt" => '>',
+                    "quot" => '"',
+                    "apos" => '\'',
+                    entity => {
+                        let code = u32::from_str_radix(entity.trim_start_matches("#x"), 16).unwrap();
+                        let code = if (0x2400..0x2420).contains(&code) { code - 0x2400 } else { code };
+                        char::from_u32(code).unwrap()
+                    }
+                };
+                address.push(ch);
+                text = &text[end + 1..];
+            }
+            address.push_str(text);
+            address
+        })
+        .collect()
+}
+
+fn corpus_addresses() -> Vec<String> {
+    let local_parts = [read_corpus("valid_local_parts.txt"), read_corpus("invalid_local_parts.txt")].concat();
+    let domains = [read_corpus("valid_domains.txt"), read_corpus("invalid_domains.txt")].concat();
+    let mut addresses = read_is_email_addresses();
+    for local_part in &local_parts {
+        for domain in &domains {
+            addresses.push(format!("{}@{}", local_part, domain));
+        }
+    }
+    addresses.extend(
+        [
+            "a. b@c. d",
+            "a.(b)c@d",
+            "a (b (c (d))) @e",
+            "a(b)c@d",
+            "a.b (c)@d.e",
+            "a@b.c (d)",
+            "a@b.c(d)e",
+            "a@b-(c)d",
+            "a@b (c)-d",
+            "a@b.c d",
+            "a@b c",
+            "a@b.-c",
+            "a@b--c",
+            " \r\n a@b",
+            "a \r\n@b",
+            "a@b \r\n",
+            "a@b\r\n \r\n ",
+            "\"a\r\n b\"@c",
+            "\"a\\\u{0}\"@[b\\]c]",
+            "a@[b\\c]",
+        ]
+        .iter()
+        .map(|address| address.to_string()),
+    );
+    let near_misses: Vec<String> = addresses
+        .iter()
+        .filter_map(|address| EmailAddress::parse(address, Some(ParsingOptions::new(true))))
+        .flat_map(|email| email.near_misses(Some(ParsingOptions::new(true))))
+        .map(|near_miss| near_miss.input)
+        .collect();
+    addresses.extend(near_misses);
+    addresses
+}
+
+#[test]
+fn addresses_match_like_the_parser() {
+    let addresses = corpus_addresses();
+    for is_lax in [false, true] {
+        let regex = anchored(&RegexSource::new(RegexDialect::Rust, is_lax).address());
+        for address in &addresses {
+            assert_eq!(
+                regex.is_match(address),
+                EmailAddress::is_valid(address, Some(ParsingOptions::new(is_lax))),
+                "{:?} with is_lax: {}",
+                address,
+                is_lax
+            );
+        }
+    }
+}
+
+#[test]
+fn components_match_like_the_parser() {
+    for is_lax in [false, true] {
+        let regex_source = RegexSource::new(RegexDialect::Rust, is_lax);
+        let local_part = anchored(&regex_source.local_part());
+        let domain = anchored(&regex_source.domain());
+        for valid in read_corpus("valid_local_parts.txt") {
+            assert!(local_part.is_match(&valid), "{:?}", valid);
+        }
+        for valid in read_corpus("valid_domains.txt") {
+            assert!(domain.is_match(&valid), "{:?}", valid);
+        }
+        if !is_lax {
+            for invalid in read_corpus("invalid_local_parts.txt") {
+                assert!(!local_part.is_match(&invalid), "{:?}", invalid);
+            }
+            for invalid in read_corpus("invalid_domains.txt") {
+                assert!(!domain.is_match(&invalid), "{:?}", invalid);
+            }
+        }
+        for address in corpus_addresses() {
+            let Some(at) = address.rfind('@') else {
+                continue;
+            };
+            let (local, rest) = address.split_at(at);
+            if local_part.is_match(local) && domain.is_match(&rest[1..]) {
+                assert!(EmailAddress::is_valid(&address, Some(ParsingOptions::new(is_lax))), "{:?}", address);
+            }
+        }
+    }
+}
+
+#[test]
+fn comments_nest_up_to_the_bound() {
+    let regex_source = RegexSource {
+        max_comment_depth: 2,
+        ..RegexSource::new(RegexDialect::Rust, true)
+    };
+    let regex = anchored(&regex_source.address());
+    assert!(regex.is_match("a(b(c))@d"));
+    assert!(!regex.is_match("a(b(c(d)))@e"));
+    assert!(EmailAddress::is_valid("a(b(c(d)))@e", Some(ParsingOptions::new(true))));
+}
+
+#[cfg(feature = "proptest")]
+mod properties {
+    use super::*;
+    use email_address_parser::AddressGenerator;
+    use proptest::prelude::*;
+    use std::sync::OnceLock;
+
+    fn regexes() -> &'static (Regex, Regex) {
+        static REGEXES: OnceLock<(Regex, Regex)> = OnceLock::new();
+        REGEXES.get_or_init(|| {
+            (
+                anchored(&RegexSource::new(RegexDialect::Rust, false).address()),
+                anchored(&RegexSource::new(RegexDialect::Rust, true).address()),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn generated_addresses_match(address in AddressGenerator::new(true).strategy()) {
+            let lax = Some(ParsingOptions::new(true));
+            let (strict, regex) = regexes();
+            prop_assert_eq!(strict.is_match(&address), EmailAddress::is_valid(&address, None));
+            prop_assert!(regex.is_match(&address));
+            for near_miss in EmailAddress::parse(&address, lax.clone()).unwrap().near_misses(lax.clone()) {
+                prop_assert!(!regex.is_match(&near_miss.input), "{:?}", near_miss);
+            }
+        }
+    }
+}
+
+// Escapes a regex source as a JavaScript string literal; the sources are printable ASCII only.
+fn js_string(source: &str) -> String {
+    format!("\"{}\"", source.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[test]
+fn generated_typescript_is_up_to_date() {
+    let path = manifest_dir()
+        .join("..")
+        .join("npm-packages")
+        .join("regex")
+        .join("src")
+        .join("grammar.ts");
+    let strict = RegexSource::new(RegexDialect::EcmaScript, false);
+    let lax = RegexSource::new(RegexDialect::EcmaScript, true);
+    let constants = [
+        ("EMAIL_SOURCE_STRICT", strict.address()),
+        ("EMAIL_SOURCE_LAX", lax.address()),
+        ("LOCAL_PART_SOURCE_STRICT", strict.local_part()),
+        ("LOCAL_PART_SOURCE_LAX", lax.local_part()),
+        ("DOMAIN_SOURCE_STRICT", strict.domain()),
+        ("DOMAIN_SOURCE_LAX", lax.domain()),
+    ];
+    let mut generated = String::from(
+        "// Generated from `RegexSource` in rust-lib/src/regex_source.rs; do not edit.\n\
+         // Regenerate with `UPDATE_REGEX_SOURCES=1 cargo test --test regex_tests` in rust-lib.\n",
+    );
+    for (name, source) in &constants {
+        generated.push_str(&format!("\nexport const {} = {};\n", name, js_string(source)));
+    }
+
+    // Set `UPDATE_REGEX_SOURCES=1` to regenerate the checked-in sources.
+    if env::var_os("UPDATE_REGEX_SOURCES").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        generated,
+        "{} is stale; rerun the tests with UPDATE_REGEX_SOURCES=1",
+        path.display()
+    );
+}
+